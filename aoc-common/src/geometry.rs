@@ -0,0 +1,324 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{parse::parse_token, ParseError};
+
+/**
+ * The integer types points can be made of. Unsigned coordinates are the natural choice for grids,
+ * and signed ones for anything that can go in any direction (like day 17's probe). Moving by an
+ * offset that doesn't fit in the type (like going left from `x = 0` with `usize`) gives `None`.
+ */
+pub trait Coordinate:
+    Copy + Ord + Hash + fmt::Debug + Add<Output = Self> + Sub<Output = Self>
+{
+    const ONE: Self;
+
+    fn checked_offset(self, offset: isize) -> Option<Self>;
+
+    /**
+     * `|self - other|`, which works for unsigned types too.
+     */
+    fn distance(self, other: Self) -> Self {
+        self.max(other) - self.min(other)
+    }
+}
+
+macro_rules! coordinate {
+    ( $( $type:ty ),* ) => {
+        $(
+            impl Coordinate for $type {
+                const ONE: Self = 1;
+
+                fn checked_offset(self, offset: isize) -> Option<Self> {
+                    let value = isize::try_from(self).ok()?.checked_add(offset)?;
+                    <$type>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/**
+ * A point in 2D, also used as a vector for offsets and directions. Like in `Grid`, `y` grows
+ * downwards.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/**
+ * The offsets to the points up, left, right and down of another point.
+ */
+pub const NEIGHBORS: [Point<isize>; 4] = [
+    Point::new(0, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(0, 1),
+];
+
+/**
+ * Like `NEIGHBORS`, including the diagonals, row by row.
+ */
+pub const NEIGHBORS_WITH_DIAGONALS: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    pub fn manhattan_distance(self, other: Point<T>) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /**
+     * The number of moves between two points when diagonal moves are allowed.
+     */
+    pub fn chebyshev_distance(self, other: Point<T>) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /**
+     * The point moved by `offset`, if it can be represented with `T`.
+     */
+    pub fn offset(self, offset: Point<isize>) -> Option<Point<T>> {
+        Some(Point::new(
+            self.x.checked_offset(offset.x)?,
+            self.y.checked_offset(offset.y)?,
+        ))
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Point<T>> {
+        NEIGHBORS
+            .iter()
+            .filter_map(move |offset| self.offset(*offset))
+    }
+
+    pub fn neighbors_with_diagonals(self) -> impl Iterator<Item = Point<T>> {
+        NEIGHBORS_WITH_DIAGONALS
+            .iter()
+            .filter_map(move |offset| self.offset(*offset))
+    }
+
+    /**
+     * The same point with another type of coordinates, if they fit in it.
+     */
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl<T: FromStr> Point<T>
+where
+    T::Err: fmt::Display,
+{
+    /**
+     * Parses a point like `x,y` from `token`, reporting errors at their column in `line`.
+     */
+    pub fn parse_in(line: &str, token: &str) -> Result<Self, ParseError> {
+        let (x, y) = token
+            .split_once(',')
+            .ok_or_else(|| ParseError::at(line, token, "Expected a point like `x,y`"))?;
+
+        Ok(Point::new(parse_token(line, x)?, parse_token(line, y)?))
+    }
+}
+
+impl<T: FromStr> FromStr for Point<T>
+where
+    T::Err: fmt::Display,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Point::parse_in(s, s)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:?}, {:?})", self.x, self.y)
+    }
+}
+
+/**
+ * The smallest rectangle containing some points, with both corners included.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox<T> {
+    pub min: Point<T>,
+    pub max: Point<T>,
+}
+
+impl<T: Coordinate> BoundingBox<T> {
+    /**
+     * The bounding box of all the points, or `None` if there are none.
+     */
+    pub fn of(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounding_box = BoundingBox {
+            min: first,
+            max: first,
+        };
+
+        for point in points {
+            bounding_box.min = Point::new(
+                bounding_box.min.x.min(point.x),
+                bounding_box.min.y.min(point.y),
+            );
+            bounding_box.max = Point::new(
+                bounding_box.max.x.max(point.x),
+                bounding_box.max.y.max(point.y),
+            );
+        }
+
+        Some(bounding_box)
+    }
+
+    pub fn width(&self) -> T {
+        self.max.x - self.min.x + T::ONE
+    }
+
+    pub fn height(&self) -> T {
+        self.max.y - self.min.y + T::ONE
+    }
+
+    pub fn contains(&self, point: Point<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point::new(3, -2);
+        assert_eq!(point + Point::new(1, 1), Point::new(4, -1));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, -3));
+        assert_eq!(point * 2, Point::new(6, -4));
+        assert_eq!(-point, Point::new(-3, 2));
+
+        let mut point = Point::new(1usize, 1);
+        point += Point::new(2, 3);
+        assert_eq!(point, Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new(1usize, 8);
+        let b = Point::new(4usize, 3);
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        assert_eq!(Point::new(-1, -1).manhattan_distance(Point::new(1, 1)), 4);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let corner = Point::new(0usize, 0);
+        assert_eq!(
+            corner.neighbors().collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(corner.neighbors_with_diagonals().count(), 3);
+        assert_eq!(Point::new(0isize, 0).neighbors_with_diagonals().count(), 8);
+        assert_eq!(Point::new(-1isize, 2).try_cast::<usize>(), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("6,10".parse(), Ok(Point::new(6usize, 10)));
+
+        let line = "0,9 -> 5,x";
+        let error = Point::<usize>::parse_in(line, &line[7..]).unwrap_err();
+        assert_eq!(error.column, 10);
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [Point::new(2, 5), Point::new(-1, 3), Point::new(4, 4)];
+        let bounding_box = BoundingBox::of(points).unwrap();
+        assert_eq!(bounding_box.min, Point::new(-1, 3));
+        assert_eq!(bounding_box.max, Point::new(4, 5));
+        assert_eq!((bounding_box.width(), bounding_box.height()), (6, 3));
+        assert!(bounding_box.contains(Point::new(0, 4)));
+        assert!(!bounding_box.contains(Point::new(0, 6)));
+        assert_eq!(BoundingBox::<isize>::of([]), None);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{parse::parse_lines, ParseError, Point};
+
+/**
+ * A position in a grid. `x` grows to the right and `y` grows downwards, so `(0, 0)` is the top
+ * left corner.
+ */
+pub type Position = Point<usize>;
+
+/**
+ * A rectangle of cells, stored row by row in a single `Vec`. Indexing with a `Position` panics if
+ * it's outside of the grid, like indexing a `Vec` does, while `get` returns `None` instead.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /**
+     * A grid with the given cells, row by row. There have to be exactly `width * height` cells.
+     */
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "A {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /**
+     * A grid with the value returned by `cell` for each position.
+     */
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Grid::new(width, height, cells)
+    }
+
+    /**
+     * Parses a non-empty rectangle of characters, one row per line, turning each character into a
+     * cell with `cell`. Characters it returns `None` for are reported as errors.
+     */
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let rows = parse_lines(input, |line| {
+            if *width.get_or_insert(line.chars().count()) != line.chars().count() {
+                return Err(ParseError::at(
+                    line,
+                    line,
+                    "All rows must have the same width",
+                ));
+            }
+
+            line.chars()
+                .enumerate()
+                .map(|(i, char)| {
+                    cell(char)
+                        .ok_or_else(|| ParseError::new(1, i + 1, format!("Unexpected `{}`", char)))
+                })
+                .collect::<Result<Vec<T>, _>>()
+        })?;
+
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(ParseError::new(1, 1, "The grid is empty"));
+        }
+
+        let (width, height) = (rows[0].len(), rows.len());
+        Ok(Grid::new(
+            width,
+            height,
+            rows.into_iter().flatten().collect(),
+        ))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x < self.width && position.y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self[position])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self[position])
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Every position of the grid, row by row.
+     */
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /**
+     * Every cell with its position, row by row.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /**
+     * Every cell, row by row, without their positions.
+     */
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /**
+     * The positions up, down, left and right of `position` that are inside the grid.
+     */
+    pub fn neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbors()
+            .filter(move |neighbor| self.contains(*neighbor))
+    }
+
+    /**
+     * Like `neighbors`, including the diagonals.
+     */
+    pub fn neighbors_with_diagonals(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = Position> + '_ {
+        position
+            .neighbors_with_diagonals()
+            .filter(move |neighbor| self.contains(*neighbor))
+    }
+
+    /**
+     * A grid of the same size with `f` applied to every cell.
+     */
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<usize> {
+    /**
+     * Parses a rectangle of single digits, like the height map of day 9 or the risk levels of day
+     * 15.
+     */
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |char| char.to_digit(10).map(|digit| digit as usize))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+        &self.cells[position.y * self.width + position.x]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        assert!(
+            self.contains(position),
+            "{:?} is outside of the {}x{} grid",
+            position,
+            self.width,
+            self.height
+        );
+        &mut self.cells[position.y * self.width + position.x]
+    }
+}
+
+/**
+ * Prints every row on its own line, with the cells next to each other. Grids of single characters
+ * or digits print the same way they are parsed.
+ */
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = Grid::parse_digits("12\n3x").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(Grid::parse_digits("12\n3").unwrap_err().line, 2);
+        assert!(Grid::parse_digits("").is_err());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = Grid::from_fn(3, 2, |Point { x, y }| x + 10 * y);
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&11));
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [2, 12]);
+
+        *grid.get_mut(Point::new(0, 1)).unwrap() = 5;
+        assert_eq!(grid[Point::new(0, 1)], 5);
+        assert_eq!(grid.map(|cell| cell % 2).to_string(), "010\n110\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::filled(3, 3, ());
+        assert_eq!(
+            grid.neighbors(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbors(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_with_diagonals(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbors_with_diagonals(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbors_with_diagonals(Point::new(2, 1)).count(), 5);
+    }
+}
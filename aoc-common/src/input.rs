@@ -0,0 +1,150 @@
+use std::{
+    env, fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+    process,
+};
+
+/**
+ * Where the puzzle input comes from. Each day embeds both its `input.txt` and its `sample.txt` at
+ * compile time, so running without arguments (or with `--sample`) does not depend on the current
+ * directory. Any other argument is read at runtime, with `-` meaning stdin.
+ *
+ * Days that were solved without a personal input keep an empty `input.txt`, so reading the
+ * embedded input of those days is an error asking for a path instead.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Embedded,
+    Sample,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /**
+     * Picks the source from the first command line argument, the same way for every day:
+     *
+     * ```text
+     * cargo run                 # Embedded input.txt
+     * cargo run -- --sample     # Embedded sample.txt
+     * cargo run -- -            # Read from stdin
+     * cargo run -- other.txt    # Read from the given path
+     * ```
+     */
+    pub fn from_args() -> Self {
+        Source::from_arg(env::args().nth(1).as_deref())
+    }
+
+    pub fn from_arg(arg: Option<&str>) -> Self {
+        match arg {
+            None => Source::Embedded,
+            Some("--sample") => Source::Sample,
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    pub fn read(&self, sample: &str, input: &str) -> io::Result<String> {
+        match self {
+            Source::Embedded if input.is_empty() => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "this day has no input.txt, pass the path to one",
+            )),
+            Source::Embedded => Ok(input.to_string()),
+            Source::Sample => Ok(sample.to_string()),
+            Source::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                Ok(buffer)
+            }
+            Source::Path(path) => fs::read_to_string(path),
+        }
+    }
+
+    /**
+     * Like `read`, but one line at a time, so stdin and files are read as the lines are consumed
+     * instead of all at once. Meant for inputs that may not fit in memory.
+     */
+    pub fn lines(
+        &self,
+        sample: &'static str,
+        input: &'static str,
+    ) -> io::Result<Box<dyn Iterator<Item = io::Result<String>>>> {
+        match self {
+            Source::Embedded | Source::Sample => {
+                let text = self.read(sample, input)?;
+                let lines: Vec<String> = text.lines().map(str::to_string).collect();
+                Ok(Box::new(lines.into_iter().map(Ok)))
+            }
+            Source::Stdin => Ok(Box::new(BufReader::new(io::stdin()).lines())),
+            Source::Path(path) => Ok(Box::new(BufReader::new(File::open(path)?).lines())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Embedded => write!(f, "embedded input.txt"),
+            Source::Sample => write!(f, "embedded sample.txt"),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/**
+ * Reads the puzzle input selected by the command line arguments. `sample` and `input` are the
+ * embedded files, usually passed as `include_str!("../sample.txt")` and
+ * `include_str!("../input.txt")`. A file that can't be read is a usage mistake rather than a bug,
+ * so it exits with a short message instead of panicking.
+ */
+pub fn load(sample: &str, input: &str) -> String {
+    let source = Source::from_args();
+
+    source.read(sample, input).unwrap_or_else(|error| {
+        eprintln!("Error reading {}: {}", source, error);
+        process::exit(1);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg(None), Source::Embedded);
+        assert_eq!(Source::from_arg(Some("--sample")), Source::Sample);
+        assert_eq!(Source::from_arg(Some("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Some("input.txt")),
+            Source::Path("input.txt".into())
+        );
+    }
+
+    #[test]
+    fn test_read_embedded() {
+        assert_eq!(Source::Embedded.read("sample", "input").unwrap(), "input");
+        assert_eq!(Source::Sample.read("sample", "input").unwrap(), "sample");
+        assert!(Source::Embedded.read("sample", "").is_err());
+
+        let lines = Source::Sample.lines("1\n2", "3").unwrap();
+        assert_eq!(lines.map(Result::unwrap).collect::<Vec<_>>(), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_read_path() {
+        let path = env::temp_dir().join("aoc-common-test-read-path.txt");
+        fs::write(&path, "from a file").unwrap();
+        assert_eq!(
+            Source::Path(path.clone()).read("sample", "input").unwrap(),
+            "from a file"
+        );
+        fs::remove_file(&path).unwrap();
+
+        assert!(Source::Path(path).read("sample", "input").is_err());
+    }
+}
@@ -0,0 +1,22 @@
+//! Code shared by all the days.
+//!
+//! Every day reads its puzzle input through `input::load`, so running the sample, a different file
+//! or piping something through stdin works the same way regardless of the day. Each day also
+//! implements `solution::Solution`, which is what both its own binary and the `aoc` runner call,
+//! and reports malformed input with a `parse::ParseError`. Puzzles played on a 2D map share
+//! `grid::Grid`, and anything else with 2D coordinates uses `geometry::Point`. Finding paths, be it
+//! in a grid or between states of a puzzle, goes through `search`.
+
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod lint;
+pub mod parse;
+pub mod search;
+pub mod solution;
+
+pub use geometry::Point;
+pub use grid::Grid;
+pub use lint::Assumption;
+pub use parse::ParseError;
+pub use solution::{Answer, Answers, Part, Solution, Solved, Solver};
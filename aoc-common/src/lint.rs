@@ -0,0 +1,38 @@
+use crate::Solution;
+
+/**
+ * Something a solution takes for granted about its input without checking it while parsing,
+ * usually because every input of the puzzle follows it even if the problem statement doesn't say
+ * so. Each violation describes one place where the input doesn't follow it, so the assumption
+ * holds when there are none.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assumption {
+    pub description: &'static str,
+    pub violations: Vec<String>,
+}
+
+impl Assumption {
+    pub fn new(description: &'static str, violations: impl IntoIterator<Item = String>) -> Self {
+        Assumption {
+            description,
+            violations: violations.into_iter().collect(),
+        }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/**
+ * Like `Solver`, a pointer to `lint::<DayNN>` so tooling can lint any day.
+ */
+pub type Linter = fn(&str) -> anyhow::Result<Vec<Assumption>>;
+
+/**
+ * Parses the input and checks it against the assumptions of the solution.
+ */
+pub fn lint<S: Solution>(input: &str) -> anyhow::Result<Vec<Assumption>> {
+    Ok(S::lint(&S::parse(input)?))
+}
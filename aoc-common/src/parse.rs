@@ -0,0 +1,131 @@
+use std::{error, fmt, str::FromStr};
+
+/**
+ * What every day's parser returns when the input is not what it expects, pointing at the line and
+ * column (both starting at 1) where the problem is.
+ *
+ * Most inputs are parsed one line at a time, and the code parsing a single line doesn't know which
+ * line it is. So errors are always created relative to the text being parsed, as if it started at
+ * line 1, and whoever splits the input into lines moves them to the right place with `offset`.
+ * `parse_lines` does that for the common case of one item per line.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /**
+     * An error about `token`, which has to be a slice of `line` so its column can be found.
+     */
+    pub fn at(line: &str, token: &str, message: impl Into<String>) -> Self {
+        ParseError::new(1, column(line, token), message)
+    }
+
+    /**
+     * Moves an error created for a part of the input down by the number of lines before that part.
+     */
+    pub fn offset(self, lines: usize) -> Self {
+        ParseError {
+            line: self.line + lines,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/**
+ * The column (starting at 1) where `token` starts in `line`. Tokens usually come from splitting or
+ * trimming the line, so they point inside it. Anything else is reported at the first column.
+ */
+pub fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let token_start = token.as_ptr() as usize;
+
+    if (start..=start + line.len()).contains(&token_start) {
+        token_start - start + 1
+    } else {
+        1
+    }
+}
+
+/**
+ * Parses `token` (a slice of `line`) with its `FromStr` implementation, typically a number.
+ */
+pub fn parse_token<T>(line: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    token
+        .parse()
+        .map_err(|error| ParseError::at(line, token, format!("Invalid `{}`: {}", token, error)))
+}
+
+/**
+ * Parses every line of the input on its own, fixing the line of any error.
+ */
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|error| error.offset(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column() {
+        let line = "forward 5";
+        let (_, amount) = line.split_once(' ').unwrap();
+        assert_eq!(column(line, line), 1);
+        assert_eq!(column(line, amount), 9);
+        let elsewhere = String::from("5");
+        assert_eq!(column(line, &elsewhere), 1);
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let numbers = parse_lines("1\n2\n3", |line| parse_token::<u8>(line, line));
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\n2 x", |line| {
+            line.split(' ')
+                .map(|token| parse_token::<u8>(line, token))
+                .sum::<Result<u8, _>>()
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: Invalid `x`: invalid digit found in string"
+        );
+    }
+}
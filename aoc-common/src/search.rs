@@ -0,0 +1,324 @@
+//! Searches over graphs that are only known through a function returning the neighbors of a node,
+//! so the nodes can be anything from a position in a `Grid` to the whole state of a puzzle. Nodes
+//! are cloned into the maps that keep track of the search, so they should be cheap to clone.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/**
+ * Every node found by a search, with the cost to reach it and the node it was reached from.
+ */
+#[derive(Debug, Clone)]
+pub struct Explored<N> {
+    costs: HashMap<N, usize>,
+    previous: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> Explored<N> {
+    fn new(start: N) -> Self {
+        let mut costs = HashMap::new();
+        costs.insert(start, 0);
+
+        Explored {
+            costs,
+            previous: HashMap::new(),
+        }
+    }
+
+    fn reach(&mut self, node: N, from: &N, cost: usize) {
+        self.previous.insert(node.clone(), from.clone());
+        self.costs.insert(node, cost);
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    pub fn cost(&self, node: &N) -> Option<usize> {
+        self.costs.get(node).copied()
+    }
+
+    /**
+     * Every node that was reached, including the start, in no particular order.
+     */
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.costs.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /**
+     * The nodes from the start to `node`, both included, following the cheapest way found to each
+     * of them.
+     */
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.contains(node) {
+            return None;
+        }
+
+        let mut nodes = vec![node.clone()];
+
+        while let Some(previous) = self.previous.get(nodes.last().unwrap()) {
+            nodes.push(previous.clone());
+        }
+
+        nodes.reverse();
+        Some(nodes)
+    }
+}
+
+/**
+ * The cheapest path to a goal, with the start and the goal included in `nodes`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+/**
+ * Finds every node reachable from `start`, where the cost of a node is the least number of steps
+ * needed to reach it.
+ */
+pub fn breadth_first<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Explored<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut explored = Explored::new(start.clone());
+    let mut queue = VecDeque::new();
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        let cost = explored.costs[&node] + 1;
+
+        for next in neighbors(&node) {
+            if !explored.contains(&next) {
+                explored.reach(next.clone(), &node, cost);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    explored
+}
+
+/**
+ * The cheapest path from `start` to any node for which `is_goal` is true, where `neighbors` returns
+ * each neighbor with the cost of moving to it.
+ */
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    a_star(start, neighbors, |_| 0, is_goal)
+}
+
+/**
+ * Like `dijkstra`, exploring first the nodes that look closer to a goal according to `heuristic`.
+ * The heuristic must never be more than the actual cost to reach a goal, or the path found might
+ * not be the cheapest one.
+ */
+pub fn a_star<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut explored = Explored::new(start.clone());
+    let mut queue = BinaryHeap::new();
+    queue.push(Candidate {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        // A cheaper way to this node was found after this one was queued
+        if explored.costs[&node] < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            let nodes = explored.path_to(&node).unwrap();
+            return Some(Path { cost, nodes });
+        }
+
+        for (next, step_cost) in neighbors(&node) {
+            let next_cost = cost + step_cost;
+
+            if explored.cost(&next).is_none_or(|best| next_cost < best) {
+                explored.reach(next.clone(), &node, next_cost);
+                queue.push(Candidate {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/**
+ * Counts the different paths from `start` to nodes for which `is_goal` is true. Paths end at the
+ * first goal they reach. Each node is only counted from once, so the graph can have a lot of paths
+ * as long as it doesn't have too many nodes, but it can't have cycles.
+ */
+pub fn count_paths<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> usize
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // `None` marks the nodes that are still being counted, which are only reached again through a
+    // cycle
+    fn count<N: Clone + Eq + Hash, I: IntoIterator<Item = N>>(
+        node: N,
+        neighbors: &mut impl FnMut(&N) -> I,
+        is_goal: &mut impl FnMut(&N) -> bool,
+        counts: &mut HashMap<N, Option<usize>>,
+    ) -> usize {
+        match counts.get(&node) {
+            Some(Some(count)) => return *count,
+            Some(None) => panic!("count_paths can't count the paths of a graph with cycles"),
+            None => (),
+        }
+
+        if is_goal(&node) {
+            return 1;
+        }
+
+        counts.insert(node.clone(), None);
+        let total = neighbors(&node)
+            .into_iter()
+            .map(|next| count(next, neighbors, is_goal, counts))
+            .sum();
+        counts.insert(node, Some(total));
+
+        total
+    }
+
+    count(start, &mut neighbors, &mut is_goal, &mut HashMap::new())
+}
+
+/**
+ * A node waiting in the queue of `a_star`. `BinaryHeap` pops the greatest element first, so the
+ * ordering is reversed to pop the lowest priority first.
+ */
+struct Candidate<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Candidate<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N> Eq for Candidate<N> {}
+
+impl<N> PartialOrd for Candidate<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Candidate<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::Point, Grid};
+
+    fn sample_grid() -> Grid<usize> {
+        Grid::parse_digits("1163\n1381\n2136\n3694").unwrap()
+    }
+
+    #[test]
+    fn test_breadth_first() {
+        // Anything from 6 up is a wall
+        let grid = sample_grid();
+        let explored = breadth_first(Point::new(0, 0), |position| {
+            grid.neighbors(*position)
+                .filter(|neighbor| grid[*neighbor] < 6)
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!(explored.len(), 8);
+        assert!(!explored.contains(&Point::new(3, 0)));
+        assert_eq!(explored.cost(&Point::new(2, 2)), Some(4));
+        assert_eq!(explored.path_to(&Point::new(2, 2)).unwrap().len(), 5);
+        assert_eq!(explored.path_to(&Point::new(3, 3)), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = sample_grid();
+        let target = Point::new(3, 3);
+        let neighbors = |position: &Point<usize>| {
+            grid.neighbors(*position)
+                .map(|neighbor| (neighbor, grid[neighbor]))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(Point::new(0, 0), neighbors, |position| *position == target).unwrap();
+        assert_eq!(path.cost, 17);
+        assert_eq!(path.nodes.first(), Some(&Point::new(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&target));
+        assert_eq!(
+            path.nodes[1..]
+                .iter()
+                .map(|node| grid[*node])
+                .sum::<usize>(),
+            17
+        );
+
+        let manhattan = |position: &Point<usize>| position.manhattan_distance(target);
+        let a_star_path = a_star(Point::new(0, 0), neighbors, manhattan, |p| *p == target);
+        assert_eq!(a_star_path.map(|path| path.cost), Some(17));
+        assert_eq!(dijkstra(0, |_| vec![], |node| *node == 1), None);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Paths from 0 to 4 moving forward by 1 or 2
+        let paths = count_paths(
+            0,
+            |n: &usize| vec![n + 1, n + 2].into_iter().filter(|next| *next <= 4),
+            |n| *n == 4,
+        );
+        assert_eq!(paths, 5);
+    }
+}
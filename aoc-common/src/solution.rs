@@ -0,0 +1,175 @@
+use std::{
+    fmt, process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{input, lint::Assumption, parse::ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part `{}`, expected 1 or 2", s)),
+        }
+    }
+}
+
+/**
+ * Every day implements this trait on a unit struct named after it (`day01::Day01` and so on).
+ * Parsing is kept separate from solving so the input is parsed once and shared by both parts, and
+ * so tooling can handle each step on its own. Parsing reports malformed input with a `ParseError`
+ * instead of panicking. Each part returns whatever type is natural for it, the only requirement is
+ * that it can be displayed. Whatever the parts assume about the input beyond what parsing checks
+ * can be listed in `lint`, so `aoc lint` can tell whether a new input breaks any of it.
+ */
+pub trait Solution {
+    type Input;
+    type Output1: fmt::Display;
+    type Output2: fmt::Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input) -> Self::Output1;
+    fn part_2(input: &Self::Input) -> Self::Output2;
+
+    fn lint(_input: &Self::Input) -> Vec<Assumption> {
+        vec![]
+    }
+}
+
+/**
+ * Placeholder output for a part that is not solved yet.
+ */
+pub struct Unsolved;
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/**
+ * The known answers of a day for one of its inputs, as they are displayed. Each day declares them
+ * for both its sample and its actual input (`SAMPLE_ANSWERS` and `INPUT_ANSWERS`), so `aoc verify`
+ * can catch a refactor that changes an answer. `None` means the answer is not known yet.
+ */
+pub struct Answers {
+    pub part_1: Option<&'static str>,
+    pub part_2: Option<&'static str>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part_1,
+            Part::Two => self.part_2,
+        }
+    }
+}
+
+/**
+ * The answer to one part, formatted for display, and how long it took to compute it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub elapsed: Duration,
+}
+
+/**
+ * The answers to the requested parts of a day, and how long it took to parse the input they share.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub parse_elapsed: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/**
+ * `Solution` can't be used as a trait object, so tooling that handles all days uniformly (like the
+ * `aoc` runner) stores a pointer to `solve::<DayNN>` instead.
+ */
+pub type Solver = fn(&str, &[Part]) -> anyhow::Result<Solved>;
+
+/**
+ * Parses the input once and solves each of the given parts, formatting their answers. Parsing and
+ * each part are timed separately.
+ */
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> anyhow::Result<Solved> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_elapsed = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let value = match part {
+                Part::One => S::part_1(&input).to_string(),
+                Part::Two => S::part_2(&input).to_string(),
+            };
+
+            Answer {
+                part: *part,
+                value,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Solved {
+        parse_elapsed,
+        answers,
+    })
+}
+
+/**
+ * What each day's `main` does: load the input selected by the command line arguments and print the
+ * answer for both parts.
+ */
+pub fn run<S: Solution>(sample: &str, input: &str) {
+    let file = input::load(sample, input);
+    let solved = solve::<S>(&file, &Part::ALL).unwrap_or_else(|error| {
+        eprintln!("Error parsing input: {}", error);
+        process::exit(1);
+    });
+
+    for answer in solved.answers {
+        print_answer(answer.part, &answer.value);
+    }
+}
+
+/**
+ * Answers spanning multiple lines (like day 13's folded paper) start on their own line so they
+ * are not misaligned.
+ */
+pub fn print_answer(part: Part, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
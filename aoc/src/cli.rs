@@ -0,0 +1,259 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{anyhow, bail};
+use aoc_common::{input::Source, Part};
+
+pub const USAGE: &str = "\
+Usage: aoc run [DAYS] [--part 1|2] [--sample | --input PATH] [--format table|json]
+               [--repeat N]
+       aoc verify [DAYS] [--sample]
+       aoc lint [DAYS] [--sample | --input PATH]
+
+DAYS can be a single day (15), an inclusive range (1-10) or `all` (the default).
+--input only works when running a single day, and `--input -` reads from stdin.
+--format json prints every answer as a record with its day, part and elapsed time.
+--repeat solves each day N times and reports the min / median / max time of parsing and each part.
+verify compares the answers for both the sample and the actual input with the expected ones, or
+only for the sample when given --sample.
+lint checks the input against what each day assumes about it, and reports the assumptions it
+breaks. Days that don't assume anything beyond what parsing checks are left out.";
+
+pub enum Command {
+    Run(RunOptions),
+    Verify(VerifyOptions),
+    Lint(LintOptions),
+}
+
+pub struct RunOptions {
+    pub days: RangeInclusive<u8>,
+    pub parts: Vec<Part>,
+    pub source: Source,
+    pub format: Format,
+    pub repeat: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+}
+
+pub struct VerifyOptions {
+    pub days: RangeInclusive<u8>,
+    pub sample_only: bool,
+}
+
+pub struct LintOptions {
+    pub days: RangeInclusive<u8>,
+    pub source: Source,
+}
+
+pub fn parse(args: &[String]) -> anyhow::Result<Command> {
+    let mut args = args.iter().map(String::as_str);
+
+    match args.next() {
+        Some("run") => Ok(Command::Run(parse_run_options(args)?)),
+        Some("verify") => Ok(Command::Verify(parse_verify_options(args)?)),
+        Some("lint") => Ok(Command::Lint(parse_lint_options(args)?)),
+        Some(command) => bail!("Unknown command `{}`", command),
+        None => bail!("Missing command"),
+    }
+}
+
+fn parse_run_options<'a>(mut args: impl Iterator<Item = &'a str>) -> anyhow::Result<RunOptions> {
+    let mut options = RunOptions {
+        days: 1..=25,
+        parts: Part::ALL.to_vec(),
+        source: Source::Embedded,
+        format: Format::Table,
+        repeat: 1,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--part" => {
+                let part = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for --part"))?;
+                options.parts = vec![part.parse().map_err(|error: String| anyhow!(error))?];
+            }
+            "--sample" => options.source = Source::Sample,
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for --input"))?;
+                options.source = Source::from_arg(Some(path));
+            }
+            "--format" => {
+                options.format = match args.next() {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    Some(format) => bail!("Invalid format `{}`, expected table or json", format),
+                    None => bail!("Missing value for --format"),
+                };
+            }
+            "--repeat" => {
+                options.repeat = match args.next().map(str::parse) {
+                    Some(Ok(repeat)) if repeat > 0 => repeat,
+                    Some(_) => bail!("Invalid value for --repeat, expected a positive number"),
+                    None => bail!("Missing value for --repeat"),
+                };
+            }
+            flag if flag.starts_with("--") => bail!("Unknown flag `{}`", flag),
+            days => options.days = parse_days(days)?,
+        }
+    }
+
+    if matches!(options.source, Source::Path(_) | Source::Stdin)
+        && options.days.start() != options.days.end()
+    {
+        bail!("--input can only be used when running a single day");
+    }
+
+    Ok(options)
+}
+
+fn parse_verify_options<'a>(args: impl Iterator<Item = &'a str>) -> anyhow::Result<VerifyOptions> {
+    let mut options = VerifyOptions {
+        days: 1..=25,
+        sample_only: false,
+    };
+
+    for arg in args {
+        match arg {
+            "--sample" => options.sample_only = true,
+            flag if flag.starts_with("--") => bail!("Unknown flag `{}`", flag),
+            days => options.days = parse_days(days)?,
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_lint_options<'a>(mut args: impl Iterator<Item = &'a str>) -> anyhow::Result<LintOptions> {
+    let mut options = LintOptions {
+        days: 1..=25,
+        source: Source::Embedded,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--sample" => options.source = Source::Sample,
+            "--input" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| anyhow!("Missing value for --input"))?;
+                options.source = Source::from_arg(Some(path));
+            }
+            flag if flag.starts_with("--") => bail!("Unknown flag `{}`", flag),
+            days => options.days = parse_days(days)?,
+        }
+    }
+
+    if matches!(options.source, Source::Path(_) | Source::Stdin)
+        && options.days.start() != options.days.end()
+    {
+        bail!("--input can only be used when linting a single day");
+    }
+
+    Ok(options)
+}
+
+fn parse_days(s: &str) -> anyhow::Result<RangeInclusive<u8>> {
+    let parse_day = |day: &str| -> anyhow::Result<u8> {
+        match day.parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(anyhow!(
+                "Invalid day `{}`, expected a number from 1 to 25",
+                day
+            )),
+        }
+    };
+
+    if s == "all" {
+        return Ok(1..=25);
+    }
+
+    match s.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(anyhow!(
+                    "Invalid range `{}`, the first day can't come after the last one",
+                    s
+                ));
+            }
+
+            Ok(from..=to)
+        }
+        None => {
+            let day = parse_day(s)?;
+            Ok(day..=day)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_options(args: &[&str]) -> anyhow::Result<RunOptions> {
+        parse_run_options(args.iter().copied())
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("15").unwrap(), 15..=15);
+        assert_eq!(parse_days("1-10").unwrap(), 1..=10);
+        assert_eq!(parse_days("all").unwrap(), 1..=25);
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("one").is_err());
+        assert_eq!(parse_days("7-7").unwrap(), 7..=7);
+        assert!(parse_days("10-1").is_err());
+    }
+
+    #[test]
+    fn test_parse_run_options() {
+        let options = run_options(&["15", "--part", "2", "--input", "input.txt"]).unwrap();
+        assert_eq!(options.days, 15..=15);
+        assert_eq!(options.parts, vec![Part::Two]);
+        assert_eq!(options.source, Source::Path("input.txt".into()));
+        assert_eq!(options.format, Format::Table);
+
+        let options = run_options(&["--sample"]).unwrap();
+        assert_eq!(options.days, 1..=25);
+        assert_eq!(options.parts, vec![Part::One, Part::Two]);
+        assert_eq!(options.source, Source::Sample);
+
+        let options = run_options(&["--format", "json"]).unwrap();
+        assert_eq!(options.format, Format::Json);
+        assert!(run_options(&["--format", "yaml"]).is_err());
+
+        assert_eq!(run_options(&["6"]).unwrap().repeat, 1);
+        assert_eq!(run_options(&["6", "--repeat", "10"]).unwrap().repeat, 10);
+        assert!(run_options(&["6", "--repeat", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_verify_options() {
+        let options = parse_verify_options(["1-10", "--sample"].iter().copied()).unwrap();
+        assert_eq!(options.days, 1..=10);
+        assert!(options.sample_only);
+
+        assert!(parse_verify_options(["--part", "1"].iter().copied()).is_err());
+    }
+
+    #[test]
+    fn test_input_requires_single_day() {
+        assert!(run_options(&["1-3", "--input", "-"]).is_err());
+        assert!(run_options(&["3", "--input", "-"]).is_ok());
+
+        let lint_options = |args: &[&str]| parse_lint_options(args.iter().copied());
+        assert!(lint_options(&["--input", "input.txt"]).is_err());
+        assert_eq!(
+            lint_options(&["5", "--input", "input.txt"]).unwrap().source,
+            Source::Path("input.txt".into())
+        );
+    }
+}
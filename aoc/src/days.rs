@@ -0,0 +1,75 @@
+use aoc_common::{lint::Linter, Answers, Solver};
+
+/**
+ * Everything the runner needs to know about a day: how to solve it and check its assumptions, its
+ * embedded inputs and the answers expected for each of them.
+ */
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub lint: Linter,
+    pub sample: &'static str,
+    pub input: &'static str,
+    pub sample_answers: Answers,
+    pub input_answers: Answers,
+}
+
+macro_rules! day {
+    ( $number:literal, $day:ident, $solution:ident ) => {
+        Day {
+            number: $number,
+            solve: aoc_common::solution::solve::<$day::$solution>,
+            lint: aoc_common::lint::lint::<$day::$solution>,
+            sample: $day::SAMPLE,
+            input: $day::INPUT,
+            sample_answers: $day::SAMPLE_ANSWERS,
+            input_answers: $day::INPUT_ANSWERS,
+        }
+    };
+}
+
+/**
+ * All the days that have a solution, in order. Days missing from the list are simply skipped when
+ * running a range.
+ */
+pub const DAYS: &[Day] = &[
+    day!(1, day01, Day01),
+    day!(2, day02, Day02),
+    day!(3, day03, Day03),
+    day!(4, day04, Day04),
+    day!(5, day05, Day05),
+    day!(6, day06, Day06),
+    day!(7, day07, Day07),
+    day!(8, day08, Day08),
+    day!(9, day09, Day09),
+    day!(10, day10, Day10),
+    day!(11, day11, Day11),
+    day!(12, day12, Day12),
+    day!(13, day13, Day13),
+    day!(14, day14, Day14),
+    day!(15, day15, Day15),
+    day!(16, day16, Day16),
+    day!(17, day17, Day17),
+    day!(18, day18, Day18),
+    day!(19, day19, Day19),
+    day!(20, day20, Day20),
+    day!(21, day21, Day21),
+    day!(22, day22, Day22),
+    day!(23, day23, Day23),
+    day!(24, day24, Day24),
+    day!(25, day25, Day25),
+];
+
+impl Day {
+    /**
+     * Whether the day has an embedded `input.txt`. Days solved only against their sample keep an
+     * empty one, and need an input passed explicitly.
+     */
+    pub fn has_input(&self) -> bool {
+        !self.input.is_empty()
+    }
+}
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
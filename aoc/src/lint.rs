@@ -0,0 +1,68 @@
+use anyhow::{bail, Context};
+use aoc_common::{input::Source, Assumption};
+
+use crate::{cli::LintOptions, days};
+
+/**
+ * Checks the input of every selected day against the assumptions its solution makes, and lists
+ * each of them with the places where the input breaks it. Days are picked and their input read the
+ * same way as `run` does.
+ */
+pub fn lint(options: &LintOptions) -> anyhow::Result<()> {
+    let mut assumptions = vec![];
+
+    for number in options.days.clone() {
+        let day = match days::find(number) {
+            Some(day) => day,
+            None if options.days.start() == options.days.end() => {
+                bail!("Day {} is not solved yet", number)
+            }
+            None => continue,
+        };
+
+        if options.source == Source::Embedded
+            && !day.has_input()
+            && options.days.start() != options.days.end()
+        {
+            eprintln!("Skipping day {}, it has no input.txt", number);
+            continue;
+        }
+
+        let input = options
+            .source
+            .read(day.sample, day.input)
+            .with_context(|| format!("Couldn't read {}", options.source))?;
+        let day_assumptions =
+            (day.lint)(&input).with_context(|| format!("Invalid input for day {}", number))?;
+
+        for assumption in day_assumptions {
+            print_assumption(number, &assumption);
+            assumptions.push(assumption);
+        }
+    }
+
+    let violated = assumptions
+        .iter()
+        .filter(|assumption| !assumption.holds())
+        .count();
+    println!(
+        "\n{} hold, {} violated",
+        assumptions.len() - violated,
+        violated
+    );
+
+    if violated > 0 {
+        bail!("{} assumptions don't hold for the input", violated);
+    }
+
+    Ok(())
+}
+
+fn print_assumption(day: u8, assumption: &Assumption) {
+    let status = if assumption.holds() { "ok" } else { "VIOLATED" };
+    println!("Day {:>2}  {:<8}  {}", day, status, assumption.description);
+
+    for violation in &assumption.violations {
+        println!("    {}", violation);
+    }
+}
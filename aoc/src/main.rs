@@ -0,0 +1,92 @@
+use std::{env, process};
+
+use anyhow::Context;
+use aoc_common::input::Source;
+
+mod cli;
+mod days;
+mod lint;
+mod report;
+mod timing;
+mod verify;
+
+use cli::{Command, Format, RunOptions};
+use report::Row;
+
+/**
+ * Single entry point to run any of the days, instead of going into each day's directory. Every day
+ * implements `aoc_common::Solution` in its library, and `days::DAYS` turns each of them into a
+ * plain `aoc_common::Solver` function, so the runner only needs to pick the days, read their input
+ * and collect the answers into a summary table. `verify` does the same but compares the answers
+ * with the ones each day expects, to catch regressions, and `lint` checks an input against what
+ * each day assumes about it before solving it.
+ *
+ * ```text
+ * cargo run -p aoc -- run 15 --part 2 --input path/to/input.txt
+ * cargo run -p aoc -- run 1-10 --sample
+ * cargo run --release -p aoc -- run
+ * cargo run --release -p aoc -- run --format json
+ * cargo run --release -p aoc -- run 6 --repeat 100
+ * cargo run --release -p aoc -- verify
+ * cargo run -p aoc -- lint 5 --input path/to/input.txt
+ * ```
+ */
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = cli::parse(&args).unwrap_or_else(|error| {
+        eprintln!("Error: {}\n\n{}", error, cli::USAGE);
+        process::exit(1);
+    });
+
+    let result = match command {
+        Command::Run(options) => run(&options),
+        Command::Verify(options) => verify::verify(&options),
+        Command::Lint(options) => lint::lint(&options),
+    };
+
+    if let Err(error) = result {
+        eprintln!("Error: {:#}", error);
+        process::exit(1);
+    }
+}
+
+fn run(options: &RunOptions) -> anyhow::Result<()> {
+    let mut rows = vec![];
+
+    for number in options.days.clone() {
+        let day = match days::find(number) {
+            Some(day) => day,
+            None if options.days.start() == options.days.end() => {
+                anyhow::bail!("Day {} is not solved yet", number)
+            }
+            None => continue,
+        };
+
+        if options.source == Source::Embedded
+            && !day.has_input()
+            && options.days.start() != options.days.end()
+        {
+            eprintln!("Skipping day {}, it has no input.txt", number);
+            continue;
+        }
+
+        let input = options
+            .source
+            .read(day.sample, day.input)
+            .with_context(|| format!("Couldn't read {}", options.source))?;
+        let runs = (0..options.repeat)
+            .map(|_| (day.solve)(&input, &options.parts))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .with_context(|| format!("Invalid input for day {}", number))?;
+
+        rows.push(Row::new(number, &runs));
+    }
+
+    match options.format {
+        Format::Table => report::print_table(&rows, &options.parts),
+        Format::Json => report::print_json(&rows)?,
+    }
+
+    Ok(())
+}
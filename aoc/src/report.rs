@@ -0,0 +1,158 @@
+use std::time::Duration;
+
+use aoc_common::{Part, Solved};
+use serde::Serialize;
+
+use crate::timing::Timing;
+
+/**
+ * The answers of a single day, in the same order as the parts that were requested, and how long
+ * parsing and solving each part took across all the runs.
+ */
+pub struct Row {
+    pub day: u8,
+    pub parse: Timing,
+    pub answers: Vec<TimedAnswer>,
+}
+
+pub struct TimedAnswer {
+    pub part: Part,
+    pub value: String,
+    pub timing: Timing,
+}
+
+impl Row {
+    /**
+     * Combines repeated runs of the same day. The answers are taken from the first run, they don't
+     * change from one run to the next.
+     */
+    pub fn new(day: u8, runs: &[Solved]) -> Self {
+        Row {
+            day,
+            parse: Timing::new(runs.iter().map(|solved| solved.parse_elapsed).collect()),
+            answers: runs[0]
+                .answers
+                .iter()
+                .enumerate()
+                .map(|(i, answer)| TimedAnswer {
+                    part: answer.part,
+                    value: answer.value.clone(),
+                    timing: Timing::new(
+                        runs.iter()
+                            .map(|solved| solved.answers[i].elapsed)
+                            .collect(),
+                    ),
+                })
+                .collect(),
+        }
+    }
+}
+
+/**
+ * Prints a summary table with one row per day, with the answers followed by the timings. Answers
+ * that span multiple lines (like day 13's folded paper) don't fit in a cell, so they are printed in
+ * full after the table.
+ */
+pub fn print_table(rows: &[Row], parts: &[Part]) {
+    let cell = |answer: &String| {
+        if answer.contains('\n') {
+            "(see below)".to_string()
+        } else {
+            answer.clone()
+        }
+    };
+
+    let mut columns: Vec<(String, Vec<String>)> = vec![];
+    for (i, part) in parts.iter().enumerate() {
+        let cells = rows.iter().map(|row| cell(&row.answers[i].value));
+        columns.push((format!("Part {}", part), cells.collect()));
+    }
+    columns.push((
+        "Parse".to_string(),
+        rows.iter().map(|row| row.parse.to_string()).collect(),
+    ));
+    for (i, part) in parts.iter().enumerate() {
+        let cells = rows.iter().map(|row| row.answers[i].timing.to_string());
+        columns.push((format!("Time {}", part), cells.collect()));
+    }
+
+    let widths: Vec<usize> = columns
+        .iter()
+        .map(|(header, cells)| {
+            cells
+                .iter()
+                .map(String::len)
+                .chain([header.len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let mut header_line = "Day".to_string();
+    for ((header, _), width) in columns.iter().zip(&widths) {
+        header_line += &format!("  {:<width$}", header, width = width);
+    }
+    println!("{}", header_line.trim_end());
+
+    for (i, row) in rows.iter().enumerate() {
+        let mut line = format!("{:>3}", row.day);
+        for ((_, cells), width) in columns.iter().zip(&widths) {
+            line += &format!("  {:<width$}", cells[i], width = width);
+        }
+        println!("{}", line.trim_end());
+    }
+
+    for row in rows {
+        for answer in &row.answers {
+            if answer.value.contains('\n') {
+                println!("\nDay {}, part {}:\n{}", row.day, answer.part, answer.value);
+            }
+        }
+    }
+}
+
+/**
+ * A single answer as it is printed by `--format json`. Parts are numbers rather than the `Part`
+ * enum so consumers don't need to know about it. Times are in milliseconds: `elapsed_ms` and
+ * `parse_ms` are the medians across all the runs.
+ */
+#[derive(Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    elapsed_ms: f64,
+    min_ms: f64,
+    max_ms: f64,
+    parse_ms: f64,
+    runs: usize,
+}
+
+/**
+ * Prints all the answers as a JSON array with one record per day and part, for scripts and
+ * dashboards that would otherwise have to scrape the table.
+ */
+pub fn print_json(rows: &[Row]) -> anyhow::Result<()> {
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    let records: Vec<Record> = rows
+        .iter()
+        .flat_map(|row| {
+            row.answers.iter().map(move |answer| Record {
+                day: row.day,
+                part: match answer.part {
+                    Part::One => 1,
+                    Part::Two => 2,
+                },
+                answer: &answer.value,
+                elapsed_ms: ms(answer.timing.median),
+                min_ms: ms(answer.timing.min),
+                max_ms: ms(answer.timing.max),
+                parse_ms: ms(row.parse.median),
+                runs: answer.timing.runs,
+            })
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&records)?);
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::{fmt, time::Duration};
+
+/**
+ * How long something took across repeated runs. With a single run all three values are the same.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    pub fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "Can't time zero runs");
+        samples.sort_unstable();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Timing {
+            runs: samples.len(),
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/**
+ * A single duration for a single run, or `min / median / max` otherwise.
+ */
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.runs == 1 {
+            write!(f, "{:.2?}", self.median)
+        } else {
+            write!(
+                f,
+                "{:.2?} / {:.2?} / {:.2?}",
+                self.min, self.median, self.max
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_timing() {
+        let timing = Timing::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            (timing.min, timing.median, timing.max),
+            (ms(1), ms(3), ms(5))
+        );
+        assert_eq!(timing.to_string(), "1.00ms / 3.00ms / 5.00ms");
+
+        let timing = Timing::new(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(timing.median, ms(3));
+
+        assert_eq!(Timing::new(vec![ms(2)]).to_string(), "2.00ms");
+    }
+}
@@ -0,0 +1,211 @@
+use std::fmt;
+
+use anyhow::bail;
+use aoc_common::Part;
+
+use crate::{
+    cli::VerifyOptions,
+    days::{self, Day},
+};
+
+/**
+ * The inputs embedded in every day, which are the ones with known answers.
+ */
+#[derive(Debug, Clone, Copy)]
+enum Embedded {
+    Sample,
+    Input,
+}
+
+impl fmt::Display for Embedded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Embedded::Sample => write!(f, "sample"),
+            Embedded::Input => write!(f, "input"),
+        }
+    }
+}
+
+/**
+ * The outcome of solving a single part of a day for one of its embedded inputs, next to the answer
+ * it should have produced. `actual` is `None` when there was nothing to solve, because the day has
+ * no `input.txt`.
+ */
+struct Check {
+    day: u8,
+    embedded: Embedded,
+    part: Part,
+    expected: Option<&'static str>,
+    actual: Option<Result<String, String>>,
+}
+
+impl Check {
+    fn passed(&self) -> bool {
+        match (self.expected, &self.actual) {
+            (Some(expected), Some(Ok(actual))) => expected == actual,
+            _ => false,
+        }
+    }
+
+    fn skipped(&self) -> bool {
+        match &self.actual {
+            None => true,
+            Some(actual) => self.expected.is_none() && actual.is_ok(),
+        }
+    }
+}
+
+/**
+ * Solves every selected day for its sample and actual input, and compares each answer with the
+ * one the day declares in `SAMPLE_ANSWERS` and `INPUT_ANSWERS`. Mismatches are printed as a line
+ * diff, which is mostly useful for answers spanning multiple lines like day 13's folded paper.
+ */
+pub fn verify(options: &VerifyOptions) -> anyhow::Result<()> {
+    let mut checks = vec![];
+
+    for day in days::DAYS
+        .iter()
+        .filter(|day| options.days.contains(&day.number))
+    {
+        checks.extend(check(day, Embedded::Sample));
+
+        if !options.sample_only {
+            checks.extend(check(day, Embedded::Input));
+        }
+    }
+
+    for check in &checks {
+        print_check(check);
+    }
+
+    let passed = checks.iter().filter(|check| check.passed()).count();
+    let skipped = checks.iter().filter(|check| check.skipped()).count();
+    let failed = checks.len() - passed - skipped;
+    println!(
+        "\n{} passed, {} failed, {} skipped",
+        passed, failed, skipped
+    );
+
+    if failed > 0 {
+        bail!("{} answers don't match the expected ones", failed);
+    }
+
+    Ok(())
+}
+
+fn check(day: &Day, embedded: Embedded) -> Vec<Check> {
+    let (input, answers) = match embedded {
+        Embedded::Sample => (day.sample, &day.sample_answers),
+        Embedded::Input => (day.input, &day.input_answers),
+    };
+    let check = |part: Part, actual: Option<Result<String, String>>| Check {
+        day: day.number,
+        embedded,
+        part,
+        expected: answers.get(part),
+        actual,
+    };
+
+    if let Embedded::Input = embedded {
+        if !day.has_input() {
+            return Part::ALL.iter().map(|part| check(*part, None)).collect();
+        }
+    }
+
+    match (day.solve)(input, &Part::ALL) {
+        Ok(solved) => solved
+            .answers
+            .into_iter()
+            .map(|answer| check(answer.part, Some(Ok(answer.value))))
+            .collect(),
+        Err(error) => Part::ALL
+            .iter()
+            .map(|part| check(*part, Some(Err(error.to_string()))))
+            .collect(),
+    }
+}
+
+fn print_check(check: &Check) {
+    let status = if check.passed() {
+        "ok"
+    } else if check.actual.is_none() {
+        "skipped, no input.txt"
+    } else if check.skipped() {
+        "skipped, no expected answer"
+    } else {
+        "FAILED"
+    };
+
+    println!(
+        "Day {:>2}  {:<6}  part {}  {}",
+        check.day,
+        check.embedded.to_string(),
+        check.part,
+        status
+    );
+
+    match (&check.actual, check.expected) {
+        (Some(Err(error)), _) => println!("    error: {}", error),
+        (Some(Ok(actual)), Some(expected)) if actual != expected => {
+            print!("{}", diff(expected, actual))
+        }
+        _ => {}
+    }
+}
+
+/**
+ * Line by line comparison of two answers. Lines that differ are prefixed with `-` for the expected
+ * answer and `+` for the actual one, and lines that match are kept for context.
+ */
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let mut output = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                output += &format!("      {}\n", expected);
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    output += &format!("    - {}\n", expected);
+                }
+                if let Some(actual) = actual {
+                    output += &format!("    + {}\n", actual);
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        assert_eq!(diff("1139", "1140"), "    - 1139\n    + 1140\n");
+        assert_eq!(
+            diff("#.#\n.#.", "#.#\n##.\n..."),
+            "      #.#\n    - .#.\n    + ##.\n    + ...\n"
+        );
+    }
+
+    #[test]
+    fn test_samples_match_expected_answers() {
+        for day in days::DAYS {
+            for check in check(day, Embedded::Sample) {
+                assert!(
+                    check.passed() || check.skipped(),
+                    "Day {} part {} failed: {:?}",
+                    check.day,
+                    check.part,
+                    check.actual
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,597 @@
+use std::{cmp::Ordering, collections::VecDeque, fmt};
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answers, ParseError, Solution,
+};
+use serde::Serialize;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("7"),
+    part_2: Some("5"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("1139"),
+    part_2: Some("1103"),
+};
+
+/**
+ * --- Day 1: Sonar Sweep ---
+ *
+ * The program has to find out, given a list of depths, how many times the depth increases from one
+ * step to the next. For part 2, the same is needed but taking a moving window of 3 consecutive
+ * depth measurements added up.
+ *
+ * Both parts feed the depths to a `SonarAnalyzer`, one at a time, with a window of 1 for part 1 and
+ * of 3 for part 2. The analyzer only keeps the depths of the current window and a running sum, so
+ * it can go through sonar logs of any length as they are read (`cargo run -- --window 3 -` does
+ * that with stdin). Besides the increases it counts decreases and plateaus, and keeps track of the
+ * longest run of windows that keep increasing or decreasing.
+ *
+ * A `DepthProfiler` feeds the depths to an analyzer too, and describes the sea floor on top of its
+ * report: the shallowest, deepest and mean depth of each of its windows, the biggest changes from
+ * one depth to the next, and the depths that are too far from the average of the window right
+ * before them (`cargo run -- --profile` prints it).
+ */
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u64>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_depth)
+    }
+
+    fn part_1(measurements: &Self::Input) -> usize {
+        analyze(measurements.iter().copied(), 1).increases
+    }
+
+    fn part_2(measurements: &Self::Input) -> usize {
+        analyze(measurements.iter().copied(), 3).increases
+    }
+}
+
+pub fn parse_depth(line: &str) -> Result<u64, ParseError> {
+    parse_token(line, line)
+}
+
+/**
+ * Feeds all the depths to a `SonarAnalyzer` with the given window size.
+ */
+pub fn analyze(depths: impl IntoIterator<Item = u64>, window_size: usize) -> SonarReport {
+    let mut analyzer = SonarAnalyzer::new(window_size);
+
+    for depth in depths {
+        analyzer.push(depth);
+    }
+
+    analyzer.report().clone()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+}
+
+/**
+ * Consecutive windows that keep going in the same direction. `start` is the index of the first
+ * window, which is also the index of its first depth.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Run {
+    pub trend: Trend,
+    pub start: usize,
+    pub length: usize,
+}
+
+/**
+ * What a `SonarAnalyzer` found so far. Each window is compared with the previous one, so there's
+ * one increase, decrease or plateau less than there are windows.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SonarReport {
+    pub window_size: usize,
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    pub longest_run: Option<Run>,
+}
+
+/**
+ * The last `size` depths pushed, and their sum. Once it's full, every new depth pushes the oldest
+ * one out, so consecutive windows overlap in all but one depth.
+ */
+#[derive(Debug, Clone)]
+pub struct Window {
+    depths: VecDeque<u64>,
+    sum: u64,
+    size: usize,
+}
+
+impl Window {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0, "Windows need at least one depth");
+
+        Window {
+            depths: VecDeque::with_capacity(size + 1),
+            sum: 0,
+            size,
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        self.depths.push_back(depth);
+        self.sum += depth;
+
+        if self.depths.len() > self.size {
+            self.sum -= self.depths.pop_front().unwrap();
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.depths.len() == self.size
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum as f64 / self.depths.len() as f64
+    }
+
+    pub fn depths(&self) -> impl Iterator<Item = u64> + '_ {
+        self.depths.iter().copied()
+    }
+}
+
+/**
+ * Compares the sums of consecutive windows of depths as the depths come in, keeping only the
+ * current window around.
+ */
+pub struct SonarAnalyzer {
+    window: Window,
+    previous_sum: Option<u64>,
+    current_run: Option<Run>,
+    report: SonarReport,
+}
+
+impl SonarAnalyzer {
+    pub fn new(window_size: usize) -> Self {
+        SonarAnalyzer {
+            window: Window::new(window_size),
+            previous_sum: None,
+            current_run: None,
+            report: SonarReport {
+                window_size,
+                windows: 0,
+                increases: 0,
+                decreases: 0,
+                plateaus: 0,
+                longest_run: None,
+            },
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        self.window.push(depth);
+
+        if !self.window.is_full() {
+            return;
+        }
+
+        let index = self.report.windows;
+        self.report.windows += 1;
+        let window_sum = self.window.sum();
+
+        if let Some(previous_sum) = self.previous_sum {
+            match window_sum.cmp(&previous_sum) {
+                Ordering::Greater => {
+                    self.report.increases += 1;
+                    self.extend_run(Trend::Increasing, index);
+                }
+                Ordering::Less => {
+                    self.report.decreases += 1;
+                    self.extend_run(Trend::Decreasing, index);
+                }
+                Ordering::Equal => {
+                    self.report.plateaus += 1;
+                    self.current_run = None;
+                }
+            }
+        }
+
+        self.previous_sum = Some(window_sum);
+    }
+
+    pub fn report(&self) -> &SonarReport {
+        &self.report
+    }
+
+    /**
+     * The window ending at the last depth pushed, once enough depths have been pushed to fill it.
+     */
+    pub fn window(&self) -> Option<&Window> {
+        Some(&self.window).filter(|window| window.is_full())
+    }
+
+    /**
+     * Adds the window at `index` to the current run, or starts a new one with the previous window
+     * if it goes in another direction.
+     */
+    fn extend_run(&mut self, trend: Trend, index: usize) {
+        let run = match self.current_run {
+            Some(run) if run.trend == trend => Run {
+                length: run.length + 1,
+                ..run
+            },
+            _ => Run {
+                trend,
+                start: index - 1,
+                length: 2,
+            },
+        };
+
+        if self
+            .report
+            .longest_run
+            .is_none_or(|longest| run.length > longest.length)
+        {
+            self.report.longest_run = Some(run);
+        }
+
+        self.current_run = Some(run);
+    }
+}
+
+impl fmt::Display for SonarReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Windows of {} depths: {}",
+            self.window_size, self.windows
+        )?;
+        writeln!(f, "Increases: {}", self.increases)?;
+        writeln!(f, "Decreases: {}", self.decreases)?;
+        writeln!(f, "Plateaus: {}", self.plateaus)?;
+
+        match self.longest_run {
+            Some(run) => write!(
+                f,
+                "Longest run: {} windows {}, starting at line {}",
+                run.length,
+                match run.trend {
+                    Trend::Increasing => "increasing",
+                    Trend::Decreasing => "decreasing",
+                },
+                run.start + 1
+            ),
+            None => write!(f, "Longest run: none"),
+        }
+    }
+}
+
+/**
+ * The depths from `first_line` to `last_line`, both included. These are the same sliding windows
+ * the `SonarAnalyzer` compares (and part 2 with a size of 3), so there's one ending at each line
+ * once there are enough depths to fill it, and none if there are fewer depths than that.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WindowStats {
+    pub first_line: usize,
+    pub last_line: usize,
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+}
+
+/**
+ * A change from the depth in the previous line to the one in `line`.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Jump {
+    pub line: usize,
+    pub from: u64,
+    pub to: u64,
+}
+
+impl Jump {
+    pub fn size(&self) -> u64 {
+        self.from.max(self.to) - self.from.min(self.to)
+    }
+}
+
+/**
+ * A depth that differs from the average of the depths right before it by more than the threshold.
+ */
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Outlier {
+    pub line: usize,
+    pub depth: u64,
+    pub moving_average: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DepthProfile {
+    pub window_size: usize,
+    pub report: SonarReport,
+    /**
+     * How far from the moving average a depth can be before it's an outlier, as a fraction of the
+     * average.
+     */
+    pub threshold: f64,
+    pub windows: Vec<WindowStats>,
+    pub jumps: Vec<Jump>,
+    pub outliers: Vec<Outlier>,
+}
+
+/**
+ * Builds a `DepthProfile` as the depths come in, on top of a `SonarAnalyzer`: the profile includes
+ * its report, describes each of its windows, and the moving average outliers are compared with is
+ * the mean of its window right before each depth.
+ */
+pub struct DepthProfiler {
+    analyzer: SonarAnalyzer,
+    max_jumps: usize,
+    line: usize,
+    previous: Option<u64>,
+    profile: DepthProfile,
+}
+
+impl DepthProfiler {
+    /**
+     * A profiler with windows of `window_size` depths, keeping the `max_jumps` biggest jumps and
+     * flagging depths more than `threshold` (a fraction, like 0.1 for 10%) away from the average of
+     * the `window_size` depths before them.
+     */
+    pub fn new(window_size: usize, max_jumps: usize, threshold: f64) -> Self {
+        let analyzer = SonarAnalyzer::new(window_size);
+        let report = analyzer.report().clone();
+
+        DepthProfiler {
+            analyzer,
+            max_jumps,
+            line: 0,
+            previous: None,
+            profile: DepthProfile {
+                window_size,
+                report,
+                threshold,
+                windows: vec![],
+                jumps: vec![],
+                outliers: vec![],
+            },
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        self.line += 1;
+
+        if let Some(previous) = self.previous {
+            self.add_jump(Jump {
+                line: self.line,
+                from: previous,
+                to: depth,
+            });
+        }
+
+        if let Some(window) = self.analyzer.window() {
+            let moving_average = window.mean();
+
+            if (depth as f64 - moving_average).abs() > self.profile.threshold * moving_average {
+                self.profile.outliers.push(Outlier {
+                    line: self.line,
+                    depth,
+                    moving_average,
+                });
+            }
+        }
+
+        self.analyzer.push(depth);
+
+        if let Some(window) = self.analyzer.window() {
+            self.profile.windows.push(WindowStats {
+                first_line: self.line + 1 - window.size(),
+                last_line: self.line,
+                min: window.depths().min().unwrap(),
+                max: window.depths().max().unwrap(),
+                mean: window.mean(),
+            });
+        }
+
+        self.previous = Some(depth);
+    }
+
+    /**
+     * The profile of all the depths pushed.
+     */
+    pub fn finish(mut self) -> DepthProfile {
+        self.profile.report = self.analyzer.report().clone();
+        self.profile
+    }
+
+    /**
+     * Keeps the jumps sorted from biggest to smallest, with the earliest first among jumps of the
+     * same size.
+     */
+    fn add_jump(&mut self, jump: Jump) {
+        let jumps = &mut self.profile.jumps;
+        let position = jumps.partition_point(|other| other.size() >= jump.size());
+
+        if position < self.max_jumps {
+            jumps.insert(position, jump);
+            jumps.truncate(self.max_jumps);
+        }
+    }
+}
+
+/**
+ * Prints the report of the windows, followed by the windows, jumps and outliers as three tables.
+ */
+impl fmt::Display for DepthProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}\n", self.report)?;
+        writeln!(f, "Windows of {} depths", self.window_size)?;
+        writeln!(
+            f,
+            "{:<13}  {:>8}  {:>8}  {:>10}",
+            "Lines", "Min", "Max", "Mean"
+        )?;
+        for window in &self.windows {
+            let lines = format!("{}-{}", window.first_line, window.last_line);
+            writeln!(
+                f,
+                "{:<13}  {:>8}  {:>8}  {:>10.1}",
+                lines, window.min, window.max, window.mean
+            )?;
+        }
+
+        writeln!(f, "\nLargest jumps")?;
+        writeln!(
+            f,
+            "{:<13}  {:>8}  {:>8}  {:>10}",
+            "Line", "From", "To", "Change"
+        )?;
+        for jump in &self.jumps {
+            let change = jump.to as i128 - jump.from as i128;
+            writeln!(
+                f,
+                "{:<13}  {:>8}  {:>8}  {:>+10}",
+                jump.line, jump.from, jump.to, change
+            )?;
+        }
+
+        writeln!(
+            f,
+            "\nOutliers, more than {}% away from the average of the previous {} depths",
+            self.threshold * 100.0,
+            self.window_size
+        )?;
+        writeln!(f, "{:<13}  {:>8}  {:>10}", "Line", "Depth", "Average")?;
+        for outlier in &self.outliers {
+            writeln!(
+                f,
+                "{:<13}  {:>8}  {:>10.1}",
+                outlier.line, outlier.depth, outlier.moving_average
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        let depths = Day01::parse(SAMPLE).unwrap();
+        let report = analyze(depths.iter().copied(), 1);
+        assert_eq!(
+            (
+                report.windows,
+                report.increases,
+                report.decreases,
+                report.plateaus
+            ),
+            (10, 7, 2, 0)
+        );
+        assert_eq!(
+            report.longest_run,
+            Some(Run {
+                trend: Trend::Increasing,
+                start: 0,
+                length: 4
+            })
+        );
+
+        let report = analyze(depths.iter().copied(), 3);
+        assert_eq!(
+            (
+                report.windows,
+                report.increases,
+                report.decreases,
+                report.plateaus
+            ),
+            (8, 5, 1, 1)
+        );
+        assert_eq!(analyze(vec![1, 2], 3).windows, 0);
+    }
+
+    #[test]
+    fn test_depth_profile() {
+        let mut profiler = DepthProfiler::new(3, 2, 0.1);
+        for depth in Day01::parse(SAMPLE).unwrap() {
+            profiler.push(depth);
+        }
+        let profile = profiler.finish();
+
+        let lines: Vec<(usize, usize)> = profile
+            .windows
+            .iter()
+            .map(|window| (window.first_line, window.last_line))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                (1, 3),
+                (2, 4),
+                (3, 5),
+                (4, 6),
+                (5, 7),
+                (6, 8),
+                (7, 9),
+                (8, 10)
+            ]
+        );
+        assert_eq!((profile.windows[1].min, profile.windows[1].max), (200, 210));
+        assert_eq!(profile.windows[1].mean, 618.0 / 3.0);
+        assert_eq!(profile.report.increases, 5);
+
+        let jumps: Vec<(usize, u64)> = profile
+            .jumps
+            .iter()
+            .map(|jump| (jump.line, jump.size()))
+            .collect();
+        assert_eq!(jumps, [(7, 33), (8, 29)]);
+
+        // 240 and 269 are more than 10% deeper than the average of the three depths before them
+        let outliers: Vec<usize> = profile
+            .outliers
+            .iter()
+            .map(|outlier| outlier.line)
+            .collect();
+        assert_eq!(outliers, [7, 8]);
+    }
+
+    #[test]
+    fn test_depth_profile_window_boundary() {
+        let profile = |depths: &[u64]| {
+            let mut profiler = DepthProfiler::new(3, 5, 0.1);
+            depths.iter().for_each(|depth| profiler.push(*depth));
+            profiler.finish()
+        };
+
+        // Windows are only reported once they're full, like the ones the analyzer compares
+        assert!(profile(&[100, 200]).windows.is_empty());
+        assert_eq!(profile(&[100, 200, 300]).windows.len(), 1);
+        assert_eq!(profile(&[100, 200, 300, 400]).windows[1].first_line, 2);
+
+        // The first depth that can be an outlier is the one right after the first full window
+        assert!(profile(&[100, 100, 500]).outliers.is_empty());
+        assert_eq!(profile(&[100, 100, 100, 500]).outliers[0].line, 4);
+    }
+}
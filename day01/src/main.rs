@@ -1,66 +1,182 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::{env, process};
+
+use aoc_common::input::Source;
+use day01::{parse_depth, Day01, DepthProfiler, SonarAnalyzer, INPUT, SAMPLE};
+
+const USAGE: &str = "\
+Usage: day01 [--sample | PATH | -]
+       day01 --window N [--sample | PATH | -]
+       day01 --profile [--window N] [--jumps N] [--threshold PERCENT] [--format table|json]
+                       [--sample | PATH | -]
+
+--window analyzes windows of N depths, counting increases, decreases and plateaus.
+--profile also prints the min / max / mean depth of each of those windows (3 depths by default),
+the biggest jumps between consecutive depths (5 by default), and the depths more than PERCENT (10
+by default) away from the average of the N depths before them.";
 
 /**
- * --- Day 1: Sonar Sweep ---
- *
- * The program has to find out, given a list of depths, how many times the depth increases from one
- * step to the next. For part 2, the same is needed but taking a moving window of 3 consecutive
- * depth measurements added up.
- *
- * The solution has a single function that, given an iterator over numbers, counts how many times it
- * increases. `part_1` simply passes down the list of measurements, but `part_2` converts the list
- * to moving windows and maps each window to its sum before counting increases.
+ * The flags that describe the depths instead of solving the puzzle, in any position.
  */
-fn main() {
-    let lines: Vec<u64> = read_lines()
-        .expect("Error reading file")
-        .map(|line| {
-            line.expect("Error reading line")
-                .parse()
-                .expect("Error parsing line as number")
-        })
-        .collect();
-
-    let part_1 = part_1(lines.iter().cloned());
-    println!("Part 1: {}", part_1);
+const FLAGS: [&str; 5] = [
+    "--window",
+    "--profile",
+    "--jumps",
+    "--threshold",
+    "--format",
+];
 
-    let part_2 = part_2(&lines);
-    println!("Part 2: {}", part_2);
+enum Format {
+    Table,
+    Json,
 }
 
-fn part_1(measurements: impl Iterator<Item = u64>) -> u64 {
-    count_increases(measurements)
+enum Mode {
+    Analyze {
+        window_size: usize,
+    },
+    Profile {
+        window_size: usize,
+        jumps: usize,
+        threshold: f64,
+        format: Format,
+    },
 }
 
-fn part_2(measurements: &[u64]) -> u64 {
-    count_increases(measurements.windows(3).map(|window| window.iter().sum()))
+/**
+ * Besides the answers, `--window` and `--profile` describe the depths of the input given as the
+ * last argument that isn't a flag (the embedded one by default), reading it one line at a time.
+ */
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if !args.iter().any(|arg| FLAGS.contains(&arg.as_str())) {
+        aoc_common::solution::run::<Day01>(SAMPLE, INPUT);
+        return;
+    }
+
+    let (mode, source) = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("Error: {}\n\n{}", error, USAGE);
+        process::exit(1);
+    });
+    let depths = read_depths(source);
+
+    match mode {
+        Mode::Analyze { window_size } => {
+            let mut analyzer = SonarAnalyzer::new(window_size);
+            depths.for_each(|depth| analyzer.push(depth));
+            println!("{}", analyzer.report());
+        }
+        Mode::Profile {
+            window_size,
+            jumps,
+            threshold,
+            format,
+        } => {
+            let mut profiler = DepthProfiler::new(window_size, jumps, threshold / 100.0);
+            depths.for_each(|depth| profiler.push(depth));
+            let profile = profiler.finish();
+
+            match format {
+                Format::Table => print!("{}", profile),
+                Format::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&profile).expect("Couldn't serialize profile")
+                ),
+            }
+        }
+    }
 }
 
-fn count_increases(mut measurements: impl Iterator<Item = u64>) -> u64 {
-    let first_measurement = measurements
-        .next()
-        .expect("Unexpected empty measurements iterator");
-
-    measurements
-        .fold(
-            (0, first_measurement),
-            |(increases, previous_measurement), current_measurement| {
-                let next_increases = if current_measurement > previous_measurement {
-                    increases + 1
-                } else {
-                    increases
-                };
-
-                (next_increases, current_measurement)
-            },
-        )
-        .0
+fn parse_args(args: &[String]) -> Result<(Mode, Source), String> {
+    let profile = args.iter().any(|arg| arg == "--profile");
+    let mut args = args.iter().map(String::as_str);
+    let mut window_size = None;
+    let mut jumps = 5;
+    let mut threshold = 10.0;
+    let mut format = Format::Table;
+    let mut source = None;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", name))
+        };
+
+        match arg {
+            "--profile" => (),
+            "--window" => {
+                window_size = match value("--window")?.parse() {
+                    Ok(size) if size > 0 => Some(size),
+                    _ => return Err("--window needs a positive number of depths".to_string()),
+                }
+            }
+            "--jumps" if profile => {
+                jumps = value("--jumps")?
+                    .parse()
+                    .map_err(|_| "--jumps needs a number of jumps")?
+            }
+            "--threshold" if profile => {
+                threshold = match value("--threshold")?.parse() {
+                    Ok(threshold) if threshold >= 0.0 => threshold,
+                    _ => return Err("--threshold needs a percentage".to_string()),
+                }
+            }
+            "--format" if profile => {
+                format = match value("--format")? {
+                    "table" => Format::Table,
+                    "json" => Format::Json,
+                    other => {
+                        return Err(format!(
+                            "Invalid format `{}`, expected table or json",
+                            other
+                        ))
+                    }
+                }
+            }
+            "--jumps" | "--threshold" | "--format" => {
+                return Err(format!("{} only works with --profile", arg))
+            }
+            "--sample" | "-" => source = Some(Source::from_arg(Some(arg))),
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            path => source = Some(Source::from_arg(Some(path))),
+        }
+    }
+
+    let mode = if profile {
+        Mode::Profile {
+            window_size: window_size.unwrap_or(3),
+            jumps,
+            threshold,
+            format,
+        }
+    } else {
+        Mode::Analyze {
+            window_size: window_size.ok_or("Missing value for --window")?,
+        }
+    };
+
+    Ok((mode, source.unwrap_or(Source::Embedded)))
 }
 
-fn read_lines() -> io::Result<io::Lines<io::BufReader<File>>> {
-    let filename: String = env::args().skip(1).next().expect("Missing file path");
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+/**
+ * The depths of the input, parsed as they are read. Reading or parsing errors end the program,
+ * since there's nothing to report about part of the depths.
+ */
+fn read_depths(source: Source) -> impl Iterator<Item = u64> {
+    let exit_reading = move |source: &Source, error| -> ! {
+        eprintln!("Error reading {}: {}", source, error);
+        process::exit(1);
+    };
+
+    let lines = source
+        .lines(SAMPLE, INPUT)
+        .unwrap_or_else(|error| exit_reading(&source, error));
+
+    lines.enumerate().map(move |(i, line)| {
+        let line = line.unwrap_or_else(|error| exit_reading(&source, error));
+        parse_depth(&line).unwrap_or_else(|error| {
+            eprintln!("Error parsing input: {}", error.offset(i));
+            process::exit(1);
+        })
+    })
 }
@@ -0,0 +1,690 @@
+use aoc_common::{
+    parse::{column, parse_token},
+    Answers, ParseError, Solution,
+};
+use serde::{Serialize, Serializer};
+use std::{collections::HashMap, fmt, io, ops::RangeInclusive, str::FromStr};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("150"),
+    part_2: Some("900"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("1648020"),
+    part_2: Some("1759818555"),
+};
+
+/**
+ * --- Day 2: Dive! ---
+ *
+ * This program reads a series of instructions and moves the submarine according to them. The
+ * submarine has two coordinates - how far forward it has moved (horizontally) and how deep it goes.
+ * It always moves forward horizontally, but it can go up and down in depth.
+ *
+ * Part 1 and part 2 interpret the commands in a different way, but in both cases commands are
+ * represented by the `Command` enum. How a `Submarine` reacts to each command is up to the
+ * `NavigationModel` it navigates with: for part 1 (`Direct`) it simply goes forward, up and down
+ * according to the commands, and part 2 (`Aim`) includes the concept of "aim" that affects
+ * movements. Other models can be picked by name with `cargo run -- --model NAME`.
+ *
+ * `Submarine::record` navigates the same way but keeps a `Trajectory` with the position and aim
+ * after every command, which can be exported as CSV or JSON to plot a dive (`--track`).
+ *
+ * The input can also be a script (see `parse_script`) with comments, repeated blocks and macros,
+ * which expands into the commands. A list of commands like the puzzle input is a valid script.
+ */
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_script(input)
+    }
+
+    fn part_1(commands: &Self::Input) -> i64 {
+        let mut submarine = Submarine::default();
+        submarine.navigate(&mut Direct, commands.iter());
+        submarine.result()
+    }
+
+    fn part_2(commands: &Self::Input) -> i64 {
+        let mut submarine = Submarine::default();
+        submarine.navigate(&mut Aim, commands.iter());
+        submarine.result()
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Submarine {
+    pub position: Position,
+    pub aim: i64,
+}
+
+impl Submarine {
+    pub fn result(&self) -> i64 {
+        self.position.depth * self.position.horizontal as i64
+    }
+
+    pub fn navigate<'a, M: NavigationModel + ?Sized>(
+        &mut self,
+        model: &mut M,
+        commands: impl Iterator<Item = &'a Command>,
+    ) {
+        for command in commands {
+            model.execute(self, command);
+        }
+    }
+
+    /**
+     * Like `navigate`, but keeping where the submarine was after each command.
+     */
+    pub fn record<'a, M: NavigationModel + ?Sized>(
+        &mut self,
+        model: &mut M,
+        commands: impl Iterator<Item = &'a Command>,
+    ) -> Trajectory {
+        let start = *self;
+        let steps = commands
+            .map(|command| {
+                model.execute(self, command);
+                Step {
+                    command: *command,
+                    submarine: *self,
+                }
+            })
+            .collect();
+
+        Trajectory { start, steps }
+    }
+}
+
+/**
+ * Every state a submarine went through while navigating. Steps are numbered from 1, with step `n`
+ * being the state after the `n`th command, and step 0 the state before any of them.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Trajectory {
+    pub start: Submarine,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Step {
+    pub command: Command,
+    pub submarine: Submarine,
+}
+
+impl Trajectory {
+    /**
+     * The recorded submarine after the given number of commands (the starting one for step 0), or
+     * `None` if there weren't that many commands.
+     */
+    pub fn submarine_at(&self, step: usize) -> Option<Submarine> {
+        match step {
+            0 => Some(self.start),
+            _ => self.steps.get(step - 1).map(|step| step.submarine),
+        }
+    }
+
+    /**
+     * The deepest the submarine went and the first step where it got there.
+     */
+    pub fn max_depth(&self) -> (i64, usize) {
+        let depths = self.states().map(|submarine| submarine.position.depth);
+
+        depths
+            .enumerate()
+            .fold((self.start.position.depth, 0), |deepest, (step, depth)| {
+                if depth > deepest.0 {
+                    (depth, step)
+                } else {
+                    deepest
+                }
+            })
+    }
+
+    /**
+     * One line per step, including step 0, with a header.
+     */
+    pub fn write_csv(&self, out: &mut impl io::Write) -> io::Result<()> {
+        writeln!(out, "step,command,horizontal,depth,aim")?;
+
+        let commands =
+            std::iter::once(None).chain(self.steps.iter().map(|step| Some(step.command)));
+        for (step, (command, submarine)) in commands.zip(self.states()).enumerate() {
+            let command = command.map(|command| command.to_string());
+            writeln!(
+                out,
+                "{},{},{},{},{}",
+                step,
+                command.unwrap_or_default(),
+                submarine.position.horizontal,
+                submarine.position.depth,
+                submarine.aim
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn states(&self) -> impl Iterator<Item = Submarine> + '_ {
+        std::iter::once(self.start).chain(self.steps.iter().map(|step| step.submarine))
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub horizontal: u64,
+    pub depth: i64,
+}
+
+/**
+ * A way of interpreting the commands. Models can keep state of their own between commands, which
+ * is why they're borrowed mutably while navigating.
+ */
+pub trait NavigationModel {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command);
+}
+
+impl<M: NavigationModel + ?Sized> NavigationModel for Box<M> {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command) {
+        (**self).execute(submarine, command)
+    }
+}
+
+/**
+ * The names of the models `model` knows about, for listing them.
+ */
+pub const MODEL_NAMES: &[&str] = &["direct", "aim", "momentum"];
+
+/**
+ * A new model from its name in `MODEL_NAMES`.
+ */
+pub fn model(name: &str) -> Option<Box<dyn NavigationModel>> {
+    match name {
+        "direct" => Some(Box::new(Direct)),
+        "aim" => Some(Box::new(Aim)),
+        "momentum" => Some(Box::new(Momentum::default())),
+        _ => None,
+    }
+}
+
+/**
+ * Part 1: `forward` moves the submarine forward, and `down` and `up` change its depth.
+ */
+pub struct Direct;
+
+impl NavigationModel for Direct {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command) {
+        match command {
+            Command::Forward(n) => submarine.position.horizontal += n,
+            Command::Down(n) => submarine.position.depth += *n as i64,
+            Command::Up(n) => submarine.position.depth -= *n as i64,
+        }
+    }
+}
+
+/**
+ * Part 2: `down` and `up` change the aim, and `forward` moves the submarine forward and as many
+ * times the aim in depth.
+ */
+pub struct Aim;
+
+impl NavigationModel for Aim {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command) {
+        match command {
+            Command::Forward(n) => {
+                submarine.position.horizontal += n;
+                submarine.position.depth += submarine.aim * *n as i64;
+            }
+            Command::Down(n) => submarine.aim += *n as i64,
+            Command::Up(n) => submarine.aim -= *n as i64,
+        }
+    }
+}
+
+/**
+ * Like `Aim`, but the aim has momentum: `down` and `up` change how fast the aim turns instead of
+ * the aim itself, and the aim keeps turning at that rate after every command until it's
+ * compensated.
+ */
+#[derive(Default)]
+pub struct Momentum {
+    pub turn_rate: i64,
+}
+
+impl NavigationModel for Momentum {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command) {
+        match command {
+            Command::Forward(n) => {
+                submarine.position.horizontal += n;
+                submarine.position.depth += submarine.aim * *n as i64;
+            }
+            Command::Down(n) => self.turn_rate += *n as i64,
+            Command::Up(n) => self.turn_rate -= *n as i64,
+        }
+
+        submarine.aim += self.turn_rate;
+    }
+}
+
+/**
+ * Wraps another model to keep the depth within a range, stopping the submarine at its limits after
+ * each command. `surface` keeps it from going above the surface (negative depths), which the other
+ * models allow.
+ */
+pub struct Bounded<M> {
+    model: M,
+    depths: RangeInclusive<i64>,
+}
+
+impl<M: NavigationModel> Bounded<M> {
+    pub fn new(model: M, depths: RangeInclusive<i64>) -> Self {
+        Bounded { model, depths }
+    }
+
+    pub fn surface(model: M) -> Self {
+        Bounded::new(model, 0..=i64::MAX)
+    }
+}
+
+impl<M: NavigationModel> NavigationModel for Bounded<M> {
+    fn execute(&mut self, submarine: &mut Submarine, command: &Command) {
+        self.model.execute(submarine, command);
+        submarine.position.depth = submarine
+            .position
+            .depth
+            .clamp(*self.depths.start(), *self.depths.end());
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(u64),
+    Down(u64),
+    Up(u64),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split(' ').collect();
+        let (direction, value) = match &parts[..] {
+            &[direction, value, ..] => (direction, value),
+            _ => return Err(ParseError::at(s, s, "Expected a direction and a value")),
+        };
+        let value = parse_token(s, value)?;
+
+        match direction {
+            "forward" => Ok(Command::Forward(value)),
+            "down" => Ok(Command::Down(value)),
+            "up" => Ok(Command::Up(value)),
+            _ => Err(ParseError::at(
+                s,
+                direction,
+                format!("Invalid direction `{}`", direction),
+            )),
+        }
+    }
+}
+
+/**
+ * The most commands a script can hold while it's expanded, counting both the commands it expands
+ * into and the ones stored in the bodies of its macros, so a few nested `repeat` blocks or a lot of
+ * big macros can't use up all the memory.
+ */
+pub const MAX_SCRIPT_COMMANDS: usize = 10_000_000;
+
+/**
+ * Expands a dive script into the commands it's made of. Scripts have one statement per line:
+ *
+ * - A command, like `forward 5`.
+ * - `repeat N {`, followed by the statements to repeat N times and a closing `}` on its own line.
+ * - `macro NAME {`, followed by statements and a closing `}`, which defines a macro. Macros can
+ *   only be defined outside of any block, and are used by writing their name on a line after that.
+ *
+ * Anything after a `#` is a comment, and blank lines are ignored.
+ *
+ * ```
+ * let commands = day02::parse_script(
+ *     "macro dive {\n  down 2\n  forward 1 # Keep moving\n}\n\nrepeat 3 {\n  dive\n}\nup 6",
+ * )
+ * .unwrap();
+ * assert_eq!(commands.len(), 7);
+ * ```
+ */
+pub fn parse_script(script: &str) -> Result<Vec<Command>, ParseError> {
+    let mut parser = ScriptParser::default();
+
+    for (i, line) in script.lines().enumerate() {
+        parser.statement(line).map_err(|error| error.offset(i))?;
+        parser.line += 1;
+    }
+
+    match parser.blocks.last() {
+        Some(block) => Err(ParseError::new(
+            block.line + 1,
+            block.column,
+            "This block is never closed",
+        )),
+        None => Ok(parser.commands),
+    }
+}
+
+#[derive(Default)]
+struct ScriptParser<'a> {
+    macros: HashMap<&'a str, Vec<Command>>,
+    blocks: Vec<Block<'a>>,
+    commands: Vec<Command>,
+    line: usize,
+    /**
+     * How many commands are held in `commands`, the open blocks and the macros, all together.
+     */
+    stored: usize,
+}
+
+/**
+ * A `repeat` or `macro` block that's still open, with the commands so far and where it started.
+ */
+struct Block<'a> {
+    kind: BlockKind<'a>,
+    commands: Vec<Command>,
+    line: usize,
+    column: usize,
+}
+
+enum BlockKind<'a> {
+    Repeat(usize),
+    Macro(&'a str),
+}
+
+impl<'a> ScriptParser<'a> {
+    fn statement(&mut self, line: &'a str) -> Result<(), ParseError> {
+        let statement = line.split('#').next().unwrap_or_default().trim();
+
+        if statement.is_empty() {
+            Ok(())
+        } else if statement == "}" {
+            self.close_block(line, statement)
+        } else if let Some(header) = statement.strip_suffix('{') {
+            self.open_block(line, header.trim_end(), statement)
+        } else if let Some(commands) = self.macros.get(statement) {
+            let commands = commands.clone();
+            self.push(line, statement, &commands)
+        } else if !statement.contains(char::is_whitespace) && is_macro_name(statement) {
+            Err(ParseError::at(
+                line,
+                statement,
+                format!("Unknown macro `{}`", statement),
+            ))
+        } else {
+            let command = statement.parse().map_err(|error: ParseError| ParseError {
+                column: error.column + column(line, statement) - 1,
+                ..error
+            })?;
+            self.push(line, statement, &[command])
+        }
+    }
+
+    fn open_block(
+        &mut self,
+        line: &str,
+        header: &'a str,
+        statement: &str,
+    ) -> Result<(), ParseError> {
+        let tokens: Vec<&str> = header.split_whitespace().collect();
+        let kind = match tokens[..] {
+            ["repeat", times] => BlockKind::Repeat(parse_token(line, times)?),
+            ["macro", _] if !self.blocks.is_empty() => {
+                return Err(ParseError::at(
+                    line,
+                    statement,
+                    "Macros can only be defined outside of blocks",
+                ))
+            }
+            ["macro", name] if !is_macro_name(name) => {
+                return Err(ParseError::at(
+                    line,
+                    name,
+                    format!("Invalid macro name `{}`", name),
+                ))
+            }
+            ["macro", name] if self.macros.contains_key(name) => {
+                return Err(ParseError::at(
+                    line,
+                    name,
+                    format!("Macro `{}` is already defined", name),
+                ))
+            }
+            ["macro", name] => BlockKind::Macro(name),
+            _ => {
+                return Err(ParseError::at(
+                    line,
+                    statement,
+                    "Expected a block like `repeat N {` or `macro NAME {`",
+                ))
+            }
+        };
+
+        self.blocks.push(Block {
+            kind,
+            commands: vec![],
+            line: self.line,
+            column: column(line, statement),
+        });
+        Ok(())
+    }
+
+    fn close_block(&mut self, line: &str, statement: &str) -> Result<(), ParseError> {
+        let block = self
+            .blocks
+            .pop()
+            .ok_or_else(|| ParseError::at(line, statement, "There's no block to close"))?;
+
+        match block.kind {
+            BlockKind::Repeat(times) => {
+                // The block's commands are replaced with the repeated ones
+                self.stored -= block.commands.len();
+                let repeated = (block.commands.len())
+                    .checked_mul(times)
+                    .filter(|length| self.stored + length <= MAX_SCRIPT_COMMANDS)
+                    .ok_or_else(|| too_many_commands(line, statement))?;
+
+                let mut commands = Vec::with_capacity(repeated);
+                for _ in 0..times {
+                    commands.extend_from_slice(&block.commands);
+                }
+                self.push(line, statement, &commands)
+            }
+            BlockKind::Macro(name) => {
+                self.macros.insert(name, block.commands);
+                Ok(())
+            }
+        }
+    }
+
+    /**
+     * Adds commands to the innermost open block, or to the script if there's none, as long as they
+     * fit in `MAX_SCRIPT_COMMANDS`.
+     */
+    fn push(
+        &mut self,
+        line: &str,
+        statement: &str,
+        commands: &[Command],
+    ) -> Result<(), ParseError> {
+        let current = match self.blocks.last_mut() {
+            Some(block) => &mut block.commands,
+            None => &mut self.commands,
+        };
+
+        if self.stored + commands.len() > MAX_SCRIPT_COMMANDS {
+            return Err(too_many_commands(line, statement));
+        }
+
+        current.extend_from_slice(commands);
+        self.stored += commands.len();
+        Ok(())
+    }
+}
+
+fn too_many_commands(line: &str, statement: &str) -> ParseError {
+    ParseError::at(
+        line,
+        statement,
+        format!(
+            "The script and its macros hold more than {} commands",
+            MAX_SCRIPT_COMMANDS
+        ),
+    )
+}
+
+/**
+ * Macro names are made of letters, digits, `_` and `-`, and can't be a direction or a keyword.
+ */
+fn is_macro_name(name: &str) -> bool {
+    !["forward", "down", "up", "repeat", "macro"].contains(&name)
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(n) => write!(f, "forward {}", n),
+            Command::Down(n) => write!(f, "down {}", n),
+            Command::Up(n) => write!(f, "up {}", n),
+        }
+    }
+}
+
+/**
+ * Commands are written the same way as in the input, like `forward 5`.
+ */
+impl Serialize for Command {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn navigate(model: &mut impl NavigationModel, input: &str) -> Submarine {
+        let commands = Day02::parse(input).unwrap();
+        let mut submarine = Submarine::default();
+        submarine.navigate(model, commands.iter());
+        submarine
+    }
+
+    #[test]
+    fn test_momentum() {
+        let submarine = navigate(
+            &mut Momentum::default(),
+            "down 1\nforward 2\nup 2\nforward 3",
+        );
+
+        assert_eq!(submarine.position.horizontal, 5);
+        assert_eq!(submarine.position.depth, 2 + 3);
+        assert_eq!(submarine.aim, 0);
+    }
+
+    #[test]
+    fn test_bounded() {
+        let input = "up 3\nforward 2\ndown 10\nforward 2";
+
+        let surface = navigate(&mut Bounded::surface(Direct), input);
+        assert_eq!(surface.position.depth, 10);
+
+        let bounded = navigate(&mut Bounded::new(Aim, 0..=15), input);
+        assert_eq!(bounded.position.depth, 14);
+
+        let unbounded = navigate(&mut Aim, input);
+        assert_eq!(unbounded.position.depth, 8);
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let commands = Day02::parse(SAMPLE).unwrap();
+        let trajectory = Submarine::default().record(&mut Aim, commands.iter());
+
+        assert_eq!(trajectory.steps.len(), 6);
+        assert_eq!(trajectory.submarine_at(3).unwrap().position.depth, 40);
+        assert_eq!(trajectory.submarine_at(6).unwrap().result(), 900);
+        assert_eq!(trajectory.submarine_at(7), None);
+        assert_eq!(trajectory.max_depth(), (60, 6));
+
+        let mut csv = vec![];
+        trajectory.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines[1], "0,,0,0,0");
+        assert_eq!(lines[3], "2,down 5,5,0,5");
+    }
+
+    #[test]
+    fn test_script() {
+        let script = "\
+# Zig-zag down
+macro zig {
+    down 2   # Steeper
+    forward 3
+}
+
+repeat 2 {
+    zig
+    repeat 2 {
+        up 1
+    }
+}
+forward 1";
+        let commands = parse_script(script).unwrap();
+        let expected = "down 2,forward 3,up 1,up 1,down 2,forward 3,up 1,up 1,forward 1";
+        let commands: Vec<String> = commands.iter().map(Command::to_string).collect();
+        assert_eq!(commands.join(","), expected);
+
+        let error = |script| {
+            let error = parse_script(script).unwrap_err();
+            (error.line, error.column, error.message)
+        };
+        assert_eq!(
+            error("repeat 2 {\n  dive\n}"),
+            (2, 3, "Unknown macro `dive`".to_string())
+        );
+        assert_eq!(
+            error("forward 1\n  sideways 2"),
+            (2, 3, "Invalid direction `sideways`".to_string())
+        );
+        assert_eq!(
+            error("forward 1\n  repeat 2 {\n    up 1"),
+            (2, 3, "This block is never closed".to_string())
+        );
+        assert_eq!(error("}"), (1, 1, "There's no block to close".to_string()));
+        let too_many = format!(
+            "The script and its macros hold more than {} commands",
+            MAX_SCRIPT_COMMANDS
+        );
+        assert_eq!(
+            error("repeat 100000 {\n  repeat 1000 {\n    up 1\n  }\n}").2,
+            too_many
+        );
+
+        // Macros count even if they're never used
+        let big_macro =
+            |name| format!("macro {} {{\n  repeat 4000000 {{\n    up 1\n  }}\n}}", name);
+        let script = format!("{}\n{}\n{}", big_macro("a"), big_macro("b"), big_macro("c"));
+        assert_eq!(error(&script), (14, 3, too_many));
+    }
+}
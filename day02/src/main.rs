@@ -1,109 +1,149 @@
-use anyhow::{anyhow, bail};
-use std::io::BufRead;
-use std::str::FromStr;
-use std::{env, fs::File, io};
+use std::{env, io, process};
+
+use aoc_common::{input::Source, Solution};
+use day02::{Bounded, Day02, NavigationModel, Submarine, INPUT, MODEL_NAMES, SAMPLE};
+
+const USAGE: &str = "\
+Usage: day02 [--sample | PATH | -]
+       day02 --model NAME [--surface] [--max-depth N] [--track csv|json] [--sample | PATH | -]
+
+--model navigates with the model called NAME (direct, aim or momentum) instead of solving both
+parts. --surface keeps the submarine from going above the surface, and --max-depth from going
+deeper than N. --track prints the position and aim after every command instead of only the last
+ones.
+
+The input can be a dive script with comments, `repeat N {` blocks and macros (see the README).";
 
 /**
- * --- Day 2: Dive! ---
- *
- * This program reads a series of instructions and moves the submarine according to them. The
- * submarine has two coordinates - how far forward it has moved (horizontally) and how deep it goes.
- * It always moves forward horizontally, but it can go up and down in depth.
- *
- * Part 1 and part 2 interpret the commands in a different way, but in both cases commands are
- * represented by the `Command` enum. For part 1 the submarine simply goes forward, up and down
- * according to the commands. Part 2 includes the concept of "aim" that affects movements, and the
- * `Submarine` struct is in charge of keeping track of.
+ * The flags that navigate with a single model instead of solving the puzzle, in any position.
  */
+const FLAGS: [&str; 4] = ["--model", "--surface", "--max-depth", "--track"];
 
-fn main() {
-    let commands: Vec<Command> = read_lines()
-        .expect("Error reading file")
-        .map(|line| {
-            line.expect("Error reading line")
-                .parse::<Command>()
-                .expect("Error parsing command")
-        })
-        .collect();
-
-    let mut submarine = Submarine::default();
-    submarine.navigate(commands.iter());
-    println!("Part 1: {}", submarine.result());
-
-    let mut submarine = Submarine::default();
-    submarine.navigate_with_aim(commands.iter());
-    println!("Part 2: {}", submarine.result());
+enum Format {
+    Csv,
+    Json,
 }
 
-#[derive(Default)]
-struct Submarine {
-    position: Position,
-    aim: i64,
+struct Options {
+    model: Box<dyn NavigationModel>,
+    track: Option<Format>,
+    source: Source,
 }
 
-impl Submarine {
-    fn result(&self) -> i64 {
-        self.position.depth * self.position.horizontal as i64
+/**
+ * Besides the answers, `--model` prints where the submarine ends up when navigating with any of
+ * the models, and how deep it went. With `--track` it prints the whole trajectory instead.
+ */
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if !args.iter().any(|arg| FLAGS.contains(&arg.as_str())) {
+        aoc_common::solution::run::<Day02>(SAMPLE, INPUT);
+        return;
     }
 
-    fn navigate<'a>(&mut self, commands: impl Iterator<Item = &'a Command>) {
-        for command in commands {
-            match command {
-                Command::Forward(n) => self.position.horizontal += n,
-                Command::Down(n) => self.position.depth += *n as i64,
-                Command::Up(n) => self.position.depth -= *n as i64,
-            }
+    let Options {
+        mut model,
+        track,
+        source,
+    } = parse_args(&args).unwrap_or_else(|error| {
+        eprintln!("Error: {}\n\n{}", error, USAGE);
+        process::exit(1);
+    });
+
+    let input = source.read(SAMPLE, INPUT).unwrap_or_else(|error| {
+        eprintln!("Error reading {}: {}", source, error);
+        process::exit(1);
+    });
+    let commands = Day02::parse(&input).unwrap_or_else(|error| {
+        eprintln!("Error parsing input: {}", error);
+        process::exit(1);
+    });
+
+    let trajectory = Submarine::default().record(&mut model, commands.iter());
+
+    match track {
+        Some(Format::Csv) => trajectory
+            .write_csv(&mut io::stdout().lock())
+            .expect("Couldn't write trajectory"),
+        Some(Format::Json) => println!(
+            "{}",
+            serde_json::to_string_pretty(&trajectory).expect("Couldn't serialize trajectory")
+        ),
+        None => {
+            let submarine = trajectory.submarine_at(commands.len()).unwrap();
+            let (max_depth, step) = trajectory.max_depth();
+
+            println!(
+                "Horizontal position: {}\nDepth: {}\nAim: {}\nResult: {}\nMax depth: {} (step {})",
+                submarine.position.horizontal,
+                submarine.position.depth,
+                submarine.aim,
+                submarine.result(),
+                max_depth,
+                step
+            );
         }
     }
+}
 
-    fn navigate_with_aim<'a>(&mut self, commands: impl Iterator<Item = &'a Command>) {
-        for command in commands {
-            match command {
-                Command::Forward(n) => {
-                    self.position.horizontal += n;
-                    self.position.depth += self.aim * *n as i64;
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter().map(String::as_str);
+    let mut model = None;
+    let mut min_depth = i64::MIN;
+    let mut max_depth = i64::MAX;
+    let mut track = None;
+    let mut source = None;
+
+    while let Some(arg) = args.next() {
+        match arg {
+            "--model" => {
+                let name = args.next().ok_or("Missing value for --model")?;
+                model = Some(day02::model(name).ok_or_else(|| {
+                    format!(
+                        "Unknown model `{}`, expected one of: {}",
+                        name,
+                        MODEL_NAMES.join(", ")
+                    )
+                })?);
+            }
+            "--surface" => min_depth = 0,
+            "--max-depth" => {
+                max_depth = args
+                    .next()
+                    .ok_or("Missing value for --max-depth")?
+                    .parse()
+                    .map_err(|_| "--max-depth needs a depth")?
+            }
+            "--track" => {
+                track = match args.next().ok_or("Missing value for --track")? {
+                    "csv" => Some(Format::Csv),
+                    "json" => Some(Format::Json),
+                    other => {
+                        return Err(format!("Invalid format `{}`, expected csv or json", other))
+                    }
                 }
-                Command::Down(n) => self.aim += *n as i64,
-                Command::Up(n) => self.aim -= *n as i64,
             }
+            "--sample" | "-" => source = Some(Source::from_arg(Some(arg))),
+            flag if flag.starts_with("--") => return Err(format!("Unknown flag `{}`", flag)),
+            path => source = Some(Source::from_arg(Some(path))),
         }
     }
-}
 
-#[derive(Default)]
-struct Position {
-    horizontal: u64,
-    depth: i64,
-}
-
-enum Command {
-    Forward(u64),
-    Down(u64),
-    Up(u64),
-}
-
-impl FromStr for Command {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.split(' ').collect();
-        let (direction, value) = match &parts[..] {
-            &[direction, value, ..] => (direction, value),
-            _ => bail!("Parse error"),
-        };
-        let value = value.parse::<u64>()?;
-
-        match direction {
-            "forward" => Ok(Command::Forward(value)),
-            "down" => Ok(Command::Down(value)),
-            "up" => Ok(Command::Up(value)),
-            _ => Err(anyhow!("Invalid direction: {}", direction)),
-        }
+    let model = model.ok_or("--surface, --max-depth and --track only work with --model")?;
+    if min_depth > max_depth {
+        return Err("--max-depth can't be above the surface".to_string());
     }
-}
 
-fn read_lines() -> io::Result<io::Lines<io::BufReader<File>>> {
-    let filename: String = env::args().skip(1).next().expect("Missing file path");
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    let model: Box<dyn NavigationModel> = if min_depth == i64::MIN && max_depth == i64::MAX {
+        model
+    } else {
+        Box::new(Bounded::new(model, min_depth..=max_depth))
+    };
+
+    Ok(Options {
+        model,
+        track,
+        source: source.unwrap_or(Source::Embedded),
+    })
 }
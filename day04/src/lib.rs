@@ -0,0 +1,298 @@
+use aoc_common::{parse::parse_token, Answers, ParseError, Solution};
+use std::{collections::VecDeque, fmt};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("4512"),
+    part_2: Some("1924"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("16674"),
+    part_2: Some("7075"),
+};
+
+/**
+ * --- Day 4: Giant Squid ---
+ *
+ * The input for the program is a series of numbers, followed by a series of bingo boards. Most of
+ * the code here is related to parsing the `Board`s out of the input, and for printing them back (
+ * which I used for debugging). Part 1 asks to check which of the boards is the first one to win (
+ * and what's the winning number) and part 2 asks for the *last* board to win (and again, with which
+ * number).
+ *
+ * `iterate_boards_in_winning_order` creates a `BoardsIterator` that takes the boards and the
+ * numbers and yields pairs of (winning number, winning board). In order to generate that, it takes
+ * the numbers from the list one by one, and for each numbers it iterates over all the boards, marks
+ * the number and checks if any board has won at that point. If it has it removes the board from the
+ * list and returns it alongside the number.
+ *
+ * With that iterator generated, part 1 takes `.next()` (the first element of the brand new
+ * iterator) and part 2 takes `.last()` (the last winning board).
+ */
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<&str> = input.lines().collect();
+
+        let numbers_line = lines
+            .first()
+            .ok_or_else(|| ParseError::new(1, 1, "Missing numbers"))?;
+        let numbers: Vec<usize> = numbers_line
+            .split(',')
+            .map(|number| parse_token(numbers_line, number))
+            .collect::<Result<_, _>>()?;
+
+        let mut boards: Vec<Board> = vec![];
+        // Boards start after the numbers and the empty line that follows them
+        let mut offset = 2;
+
+        for board_lines in lines
+            .get(offset..)
+            .unwrap_or_default()
+            .split(|line| line.is_empty())
+        {
+            if !board_lines.is_empty() {
+                boards.push(Board::new(board_lines).map_err(|error| error.offset(offset))?);
+            }
+
+            offset += board_lines.len() + 1;
+        }
+
+        if boards.is_empty() {
+            return Err(ParseError::new(
+                3,
+                1,
+                "Expected at least one board after the numbers and an empty line",
+            ));
+        }
+
+        Ok(Bingo { numbers, boards })
+    }
+
+    fn part_1(bingo: &Self::Input) -> usize {
+        let (number, winning_board) = bingo.iterate_boards_in_winning_order().next().unwrap();
+        let unmarked_sum: usize = winning_board.unmarked_numbers().iter().sum();
+        unmarked_sum * number
+    }
+
+    fn part_2(bingo: &Self::Input) -> usize {
+        let (number, winning_board) = bingo.iterate_boards_in_winning_order().last().unwrap();
+        let unmarked_sum: usize = winning_board.unmarked_numbers().iter().sum();
+        unmarked_sum * number
+    }
+}
+
+pub struct Bingo {
+    pub numbers: Vec<usize>,
+    pub boards: Vec<Board>,
+}
+
+impl Bingo {
+    pub fn iterate_boards_in_winning_order(&self) -> BoardsIterator {
+        BoardsIterator {
+            boards: self.boards.clone(),
+            numbers: VecDeque::from(self.numbers.clone()),
+            last_number: 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Cell {
+    number: usize,
+    checked: bool,
+}
+
+impl Cell {
+    fn new(number: usize) -> Self {
+        Cell {
+            number,
+            checked: false,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Board {
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Board {
+    /**
+     * Builds a board from its lines of numbers, which must make a square.
+     */
+    pub fn new(rows: &[&str]) -> Result<Self, ParseError> {
+        let built_rows: Vec<Vec<Cell>> = rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let cells: Vec<Cell> = row
+                    .split(' ')
+                    .filter(|number| !number.is_empty())
+                    .map(|number| parse_token(row, number).map(Cell::new))
+                    .collect::<Result<_, _>>()
+                    .map_err(|error| error.offset(i))?;
+
+                if cells.len() != rows.len() {
+                    return Err(ParseError::new(
+                        i + 1,
+                        1,
+                        format!("Expected {} numbers in the row", rows.len()),
+                    ));
+                }
+
+                Ok(cells)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Board { rows: built_rows })
+    }
+
+    pub fn numbers(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .flat_map(|cells| cells.iter().map(|cell| cell.number))
+            .collect()
+    }
+
+    pub fn unmarked_numbers(&self) -> Vec<usize> {
+        self.rows
+            .iter()
+            .flat_map(|cells| {
+                cells
+                    .iter()
+                    .filter(|cell| !cell.checked)
+                    .map(|cell| cell.number)
+            })
+            .collect()
+    }
+
+    pub fn check(&mut self, number: usize) {
+        for row in &mut self.rows {
+            for cell in row {
+                if cell.number == number {
+                    cell.checked = true;
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn has_won(&self) -> bool {
+        if self
+            .rows
+            .iter()
+            .any(|row| row.iter().all(|cell| cell.checked))
+        {
+            return true;
+        }
+
+        self.columns()
+            .iter()
+            .any(|column| column.iter().all(|cell| cell.checked))
+    }
+
+    fn columns(&self) -> Vec<Vec<&Cell>> {
+        let length = self.rows.first().unwrap().len();
+        let mut columns: Vec<Vec<&Cell>> = Vec::new();
+
+        for _ in 0..length {
+            columns.push(vec![]);
+        }
+
+        for row in &self.rows {
+            for (index, cell) in row.iter().enumerate() {
+                columns[index].push(cell);
+            }
+        }
+
+        columns
+    }
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Find the maximum number of digits in decimal
+        let number_width = self
+            .numbers()
+            .iter()
+            // Probably should be log10 but Rust only has it for floats
+            .map(|n| n.to_string().len())
+            .max()
+            .unwrap();
+
+        for row in &self.rows {
+            for cell in row {
+                if cell.checked {
+                    write!(
+                        f,
+                        "{:^width$}",
+                        format!("*{}*", cell.number),
+                        width = number_width + 4
+                    )?;
+                } else {
+                    write!(f, "{:^width$}", cell.number, width = number_width + 4)?;
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct BoardsIterator {
+    boards: Vec<Board>,
+    numbers: VecDeque<usize>,
+    last_number: usize,
+}
+
+impl Iterator for BoardsIterator {
+    type Item = (usize, Board);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.boards.iter().any(|board| board.has_won()) && !self.numbers.is_empty() {
+            self.last_number = self.numbers.pop_front().unwrap();
+
+            for board in &mut self.boards {
+                board.check(self.last_number);
+            }
+        }
+
+        if let Some(next_winning_board_index) = self.boards.iter().position(|board| board.has_won())
+        {
+            Some((
+                self.last_number,
+                self.boards.remove(next_winning_board_index),
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let bingo = Day04::parse(SAMPLE).unwrap();
+        assert_eq!(bingo.numbers.len(), 27);
+        assert_eq!(bingo.boards.len(), 3);
+
+        for input in ["7,4,9", "7,4,9\n", "7,4,9\n\n\n"] {
+            let error = Day04::parse(input).err().unwrap();
+            assert_eq!((error.line, error.column), (3, 1));
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use aoc_common::{parse::parse_lines, Answers, Assumption, ParseError, Point, Solution};
+use std::{cmp::Ordering, collections::HashMap, fmt, str::FromStr};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("5"),
+    part_2: Some("12"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("6189"),
+    part_2: Some("19164"),
+};
+
+/**
+ * --- Day 5: Hydrothermal Venture ---
+ *
+ * The program takes as input a list of lines (defined by start and end coordinates) and has to find
+ * how many pairs of coordinates have an overlap of 2 or more lines. Part 1 only takes into account
+ * horizontal and vertical lines, and part 2 includes diagonals (which, per the problem description,
+ * can only be in 45º slopes).
+ *
+ * The main logic for both parts takes each line and converts it to its corresponding points. It
+ * builds a HashMap where the keys are the points and the value is how many times the point shows up
+ * in the list of lines-turned-points. Then it counts how many showed up 2 or more times. Part 1
+ * filters out diagonals before running the same logic as part 2 runs with all the lines.
+ */
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Board;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Board::new(parse_lines(input, str::parse)?))
+    }
+
+    fn part_1(board: &Self::Input) -> usize {
+        board.part_1()
+    }
+
+    fn part_2(board: &Self::Input) -> usize {
+        board.part_2()
+    }
+
+    fn lint(board: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::new(
+            "Lines are horizontal, vertical or diagonal at 45º",
+            board
+                .lines
+                .iter()
+                .enumerate()
+                .filter(|(_, line)| line.is_diagonal() && !line.is_at_45_degrees())
+                .map(|(i, line)| format!("Line {}: {}", i + 1, line)),
+        )]
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    from: Point<usize>,
+    to: Point<usize>,
+}
+
+impl Line {
+    pub fn is_horizontal(&self) -> bool {
+        self.from.y == self.to.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.from.x == self.to.x
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !self.is_horizontal() && !self.is_vertical()
+    }
+
+    /**
+     * Whether the line moves as much horizontally as vertically, which is when going from one end
+     * to the other takes twice as many steps without diagonal moves as with them.
+     */
+    pub fn is_at_45_degrees(&self) -> bool {
+        self.from.manhattan_distance(self.to) == 2 * self.from.chebyshev_distance(self.to)
+    }
+
+    /**
+     * Every point of the line, from start to end. Lines are horizontal, vertical or at 45º, so each
+     * step moves by at most one in each direction, and there are as many steps as the Chebyshev
+     * distance between both ends.
+     */
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let (from, to) = (self.from, self.to);
+        let step = move |from: usize, to: usize, i: usize| match from.cmp(&to) {
+            Ordering::Less => from + i,
+            Ordering::Greater => from - i,
+            Ordering::Equal => from,
+        };
+
+        (0..=from.chebyshev_distance(to))
+            .map(move |i| Point::new(step(from.x, to.x, i), step(from.y, to.y, i)))
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once(" -> ")
+            .ok_or_else(|| ParseError::at(s, s, "Expected a line like `x1,y1 -> x2,y2`"))?;
+
+        Ok(Line {
+            from: Point::parse_in(s, from)?,
+            to: Point::parse_in(s, to)?,
+        })
+    }
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{},{} -> {},{}",
+            self.from.x, self.from.y, self.to.x, self.to.y
+        )
+    }
+}
+
+pub struct Board {
+    lines: Vec<Line>,
+}
+
+impl Board {
+    pub fn new(lines: Vec<Line>) -> Self {
+        Board { lines }
+    }
+
+    pub fn part_1(&self) -> usize {
+        self.lines
+            .iter()
+            // For part 1 we only care about lines that are either horizontal or vertical
+            .filter(|line| !line.is_diagonal())
+            .flat_map(|line| line.points())
+            .fold(
+                HashMap::<Point<usize>, usize>::new(),
+                |mut points, point| {
+                    *points.entry(point).or_insert(0) += 1;
+                    points
+                },
+            )
+            .values()
+            .filter(|count| **count >= 2)
+            .count()
+    }
+
+    pub fn part_2(&self) -> usize {
+        self.lines
+            .iter()
+            // For part 2 we use all lines
+            .flat_map(|line| line.points())
+            .fold(
+                HashMap::<Point<usize>, usize>::new(),
+                |mut points, point| {
+                    *points.entry(point).or_insert(0) += 1;
+                    points
+                },
+            )
+            .values()
+            .filter(|count| **count >= 2)
+            .count()
+    }
+}
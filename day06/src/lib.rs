@@ -0,0 +1,167 @@
+use aoc_common::{parse::parse_token, Answers, ParseError, Solution};
+use std::collections::HashMap;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("5934"),
+    part_2: Some("26984457539"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("352195"),
+    part_2: Some("1600306001288"),
+};
+
+const CYCLE_LENGTH: usize = 7;
+const EXTRA_DAYS_FOR_FIRST_OFFSPRING: usize = 2;
+
+/**
+ * --- Day 6: Lanternfish ---
+ *
+ * The program has to simulate a growing population of lanternfish, where each lanternfish spawns a
+ * new one every 7 days, the starting population is at different stages of its cycle, and the newly
+ * spawned lanternfishes have to wait an extra 2 days before spawning its first offspring.
+ *
+ * Each lanternfish is completely independent from the rest, so `population_simulation` does all the
+ * calculation based only on the starting timer for a given lanternfish (i.e. how many days until
+ * the next spawn) and the remaining simulation days.
+ *
+ * The trick to make it scale for part two is memoizing that function and choosing the parameters
+ * in a way that will repeat often. One way to think about it is that, except for the lanternfishes
+ * on the input file, every single one after that will always days_until_next_offspring of 9. So the
+ * only parameter that really changes are the remaining simulation days - which will always
+ * decrease. In part 2 the number of days is 256, so the function will be called at most 256 * 8 =
+ * 2,048 times. After that it will always use memoized results so it finishes pretty quickly
+ * (`cargo run --release -p aoc -- run 6 --repeat 100` measures it). The memoized results live in a
+ * `Simulator`, so they go away with it instead of staying around for the whole process.
+ */
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let line = lines.next().unwrap_or_default();
+
+        if let Some(i) = lines.position(|line| !line.trim().is_empty()) {
+            return Err(ParseError::new(
+                i + 2,
+                1,
+                "Expected all the timers on a single line",
+            ));
+        }
+
+        line.trim()
+            .split(',')
+            .map(|number| parse_token(line, number))
+            .collect()
+    }
+
+    fn part_1(initial_state: &Self::Input) -> usize {
+        Simulator::new().population_after(initial_state, 80)
+    }
+
+    fn part_2(initial_state: &Self::Input) -> usize {
+        Simulator::new().population_after(initial_state, 256)
+    }
+}
+
+/**
+ * Simulates lanternfish populations, remembering the population each lanternfish ends up with for
+ * the timers and days it has already simulated.
+ */
+#[derive(Debug, Default)]
+pub struct Simulator {
+    populations: HashMap<(usize, usize), usize>,
+}
+
+impl Simulator {
+    pub fn new() -> Self {
+        Simulator::default()
+    }
+
+    /**
+     * Forgets all the simulated populations.
+     */
+    pub fn clear(&mut self) {
+        self.populations.clear();
+    }
+
+    pub fn population_after(&mut self, initial_state: &[usize], simulation_days: usize) -> usize {
+        // One thing to note on the kickoff call of population_simulation for each lanternfish is
+        // that the offspring spawns the day _after_ starting_timer gets down to 0. That means that
+        // for a starting_timer of 3, it will actually be 4 days until a new offspring spawns.
+        // That's why each of these calls have to add 1 to starting_timer.
+        initial_state
+            .iter()
+            .map(|starting_timer| self.population_simulation(*starting_timer + 1, simulation_days))
+            .sum()
+    }
+
+    pub fn population_simulation(
+        &mut self,
+        days_until_next_offspring: usize,
+        simulation_days: usize,
+    ) -> usize {
+        let key = (days_until_next_offspring, simulation_days);
+
+        if let Some(population) = self.populations.get(&key) {
+            return *population;
+        }
+
+        let offspring = if simulation_days >= days_until_next_offspring {
+            // We subtract the days_until_next_offspring from the simulation_days to cover the first
+            // offspring, so dividing the remaining simulation days by 7 will give us how many more
+            // offspring will spawn. Because of that initial subtraction we're not counting the very
+            // first offspring, so we add 1 to the result.
+            (simulation_days - days_until_next_offspring) / CYCLE_LENGTH + 1
+        } else {
+            0
+        };
+
+        // The grand total of the simulation for one lanternfish is the total for each of its
+        // offspring plus itself.
+        let population = (0..offspring)
+            .map(|n| {
+                simulation_days
+                    // The remaining simulation days for each offspring is the starting simulation
+                    // days of its parent, minus the days until its parent started producing
+                    // offspring, minus 7 days for each subsequent offspring (and one extra day for
+                    // the initial hatch). We start the range at 0 above so the first offspring will
+                    // only wait for days_until_next_offspring.
+                    .checked_sub(days_until_next_offspring + n * CYCLE_LENGTH)
+                    .map(|remaining_simulation_days| {
+                        self.population_simulation(
+                            CYCLE_LENGTH + EXTRA_DAYS_FOR_FIRST_OFFSPRING,
+                            remaining_simulation_days,
+                        )
+                    })
+                    // If there aren't enough days for a full cycle, we just count the offspring
+                    // itself.
+                    .unwrap_or(1)
+            })
+            .sum::<usize>()
+            + 1;
+
+        self.populations.insert(key, population);
+        population
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day06::parse(" 3,4,x,1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Day06::parse("3,4\n\n1").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,121 @@
+use aoc_common::{parse::parse_token, Answers, ParseError, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("37"),
+    part_2: Some("168"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("339321"),
+    part_2: Some("95476244"),
+};
+
+/**
+ * --- Day 7: The Treachery of Whales ---
+ *
+ * The program takes as input a list of initial positions for a bunch of crabs in submarines. The
+ * positions are made out of a single value each, because the crabs only move in one axis. The
+ * purpose of both parts is to find what's the best position to put all crabs in to optimize fuel
+ * consumption, where how to calculate "fuel consumption" changes in part 1 and part 2.
+ *
+ * For part 1, moving 1 step costs 1 fuel. So the challenge is to find the target position that
+ * minimizes the distance to each position in the input list. Instead of checking each possible
+ * target we can calculate the *median* of the input list. This is the value that splits the list in
+ * half. This is the position that minimizes the distance - moving one position to the side will, in
+ * the best case, add 1 to the distance for every crab in one half and subtract one from the other
+ * half, ending up in the same total fuel consumption. Worst case, moving to the side will make a
+ * crab from one half cross to the other half, so if we keep moving the target in that direction the
+ * total distance will start to increase.
+ *
+ * For part 2, moving costs 1 extra fuel per step. So moving 1 step costs 1 fuel, moving 2 costs
+ * 1+2=3 fuel, moving 3 costs 1+2+3=6 fuel and so on. It might be possible to do something fancier
+ * but this implementation simply tries each position and calculates the total fuel cost of moving
+ * all crabs there. Looking at the input file the max position is less than 2,000 and there are
+ * 1,000 numbers, so the total is a little bit under 2,000,000 iterations which finishes pretty
+ * quickly. Gauss sum is used to calculate the fuel cost of moving from some position to some target
+ * in order to avoid yet another nesting level of loops.
+ */
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let line = lines.next().unwrap_or_default();
+
+        if let Some(i) = lines.position(|line| !line.trim().is_empty()) {
+            return Err(ParseError::new(
+                i + 2,
+                1,
+                "Expected all the positions on a single line",
+            ));
+        }
+
+        line.trim()
+            .split(',')
+            .map(|number| parse_token(line, number))
+            .collect()
+    }
+
+    fn part_1(positions: &Self::Input) -> usize {
+        part_1(positions)
+    }
+
+    fn part_2(positions: &Self::Input) -> usize {
+        part_2(positions)
+    }
+}
+
+pub fn part_1(positions: &[usize]) -> usize {
+    // Calculate the median for the set of numbers
+    let mut numbers = positions.to_vec();
+    numbers.sort();
+
+    let median = if numbers.len().is_multiple_of(2) {
+        (numbers[numbers.len() / 2 - 1] + numbers[numbers.len() / 2]) / 2
+    } else {
+        numbers[numbers.len() / 2]
+    };
+
+    // Calculate fuel needed to get there
+    numbers
+        .iter()
+        .map(|n| (median as isize - *n as isize).unsigned_abs())
+        .sum()
+}
+
+pub fn part_2(positions: &[usize]) -> usize {
+    let max = positions.iter().max().unwrap();
+
+    (0..=*max)
+        .map(|target| {
+            positions
+                .iter()
+                .map(|n| {
+                    let difference = (target as isize - *n as isize).unsigned_abs();
+                    difference * (difference + 1) / 2
+                })
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Day07::parse(" 3,4,x,1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 6));
+        let error = Day07::parse("3,4\n\n1").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
@@ -0,0 +1,106 @@
+use aoc_common::{grid::Position, search::breadth_first, Answers, Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("15"),
+    part_2: Some("1134"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("522"),
+    part_2: Some("916688"),
+};
+
+/**
+ * --- Day 9: Smoke Basin ---
+ *
+ * We receive as input a map of depths (each depth is a single decimal digit). The first part asks
+ * to find all the low points in the map - points that have a depth that is lower than its vertical
+ * and horizontal neighbors. This is done by the `low_points` function by iterating over each point
+ * and comparing it to its neighbors.
+ *
+ * `low_points` returns both the depth and the coordinates of the low points, so the coordinates can
+ * be used in part 2. Part 2 asks for the sizes of the "basins" of each low point. A basin is made
+ * of all neighbors of increasing depth, until reaching some point of depth 9 (which is not included
+ * in the basin). To calculate this we do a breadth first search from each low point, where the
+ * neighbors of a point are the ones that are higher than it (which by definition is all of them for
+ * a low point) and aren't a 9. The search keeps a set of the points it found, so a point that can
+ * be reached through multiple paths is only counted once. The problem statement asks for the sizes
+ * of the three largest basins so we check the size of each basin and take the highest three.
+ */
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Map;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Map::new(Grid::parse_digits(input)?))
+    }
+
+    fn part_1(map: &Self::Input) -> usize {
+        map.low_points().iter().map(|(n, _)| n + 1).sum()
+    }
+
+    fn part_2(map: &Self::Input) -> usize {
+        let mut basin_sizes: Vec<usize> = map
+            .low_points()
+            .iter()
+            .map(|(_, coords)| map.basin_size(*coords))
+            .collect();
+        basin_sizes.sort_by_key(|n| -((*n) as isize));
+        basin_sizes[0..3].iter().product()
+    }
+}
+
+pub type Coordinates = Position;
+
+pub struct Map {
+    map: Grid<usize>,
+}
+
+impl Map {
+    pub fn new(map: Grid<usize>) -> Self {
+        Map { map }
+    }
+
+    pub fn low_points(&self) -> Vec<(usize, Coordinates)> {
+        self.map
+            .iter()
+            .filter_map(|(coords, n)| {
+                if self
+                    .map
+                    .neighbors(coords)
+                    .all(|neighbor| self.map[neighbor] > *n)
+                {
+                    Some((*n, coords))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn basin_size(&self, coords: Coordinates) -> usize {
+        self.basin_map(coords).len()
+    }
+
+    /**
+     * The points reachable from `coords` by only going up, without reaching a 9.
+     */
+    pub fn basin_map(&self, coords: Coordinates) -> HashSet<Coordinates> {
+        breadth_first(coords, |coords| {
+            let n = self.map[*coords];
+            self.map
+                .neighbors(*coords)
+                .filter(move |neighbor| self.map[*neighbor] > n && self.map[*neighbor] != 9)
+        })
+        .nodes()
+        .copied()
+        .collect()
+    }
+}
@@ -0,0 +1,168 @@
+use aoc_common::{grid::Position, Answers, Grid, ParseError, Solution};
+use std::{
+    fmt::{self, Debug, Display},
+    str::FromStr,
+};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("1656"),
+    part_2: Some("195"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("1679"),
+    part_2: Some("519"),
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Map;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(map: &Self::Input) -> usize {
+        simulate(map.clone()).take(100).sum()
+    }
+
+    fn part_2(map: &Self::Input) -> usize {
+        let octopuses = map.octopuses.width() * map.octopuses.height();
+
+        simulate(map.clone())
+            .enumerate()
+            .find_map(|(i, flashes)| {
+                if flashes == octopuses {
+                    // We add 1 because i is zero sized, but the first step per the problem
+                    // definition is 1.
+                    Some(i + 1)
+                } else {
+                    None
+                }
+            })
+            .unwrap()
+    }
+}
+
+pub type Coordinates = Position;
+
+#[derive(Clone)]
+pub struct Map {
+    octopuses: Grid<Octopus>,
+}
+
+impl Map {
+    fn new(octopuses: Grid<Octopus>) -> Self {
+        Map { octopuses }
+    }
+
+    pub fn step(&mut self) -> usize {
+        let mut flashes = 0;
+
+        for octopus in self.octopuses.values_mut() {
+            octopus.energy += 1;
+        }
+
+        let mut should_check_for_new_flashes = true;
+
+        while should_check_for_new_flashes {
+            should_check_for_new_flashes = false;
+
+            for coordinates in self.octopuses.positions() {
+                let octopus = &mut self.octopuses[coordinates];
+
+                if !octopus.flashed && octopus.energy >= 10 {
+                    flashes += 1;
+                    should_check_for_new_flashes = true;
+                    octopus.flashed = true;
+
+                    let neighbors: Vec<Coordinates> = self
+                        .octopuses
+                        .neighbors_with_diagonals(coordinates)
+                        .collect();
+
+                    for neighbor in neighbors {
+                        self.octopuses[neighbor].energy += 1;
+                    }
+                }
+            }
+        }
+
+        for octopus in self.octopuses.values_mut() {
+            octopus.flashed = false;
+            if octopus.energy >= 10 {
+                octopus.energy = 0;
+            }
+        }
+
+        flashes
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Map::new(
+            Grid::parse_digits(s)?.map(|energy| Octopus::new(*energy)),
+        ))
+    }
+}
+
+impl Debug for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.octopuses)
+    }
+}
+
+#[derive(Clone)]
+struct Octopus {
+    energy: usize,
+    flashed: bool,
+}
+
+impl Octopus {
+    fn new(energy: usize) -> Octopus {
+        Octopus {
+            energy,
+            flashed: false,
+        }
+    }
+}
+
+/**
+ * Octopuses about to flash show as `X`, and the ones with even more energy as `x`.
+ */
+impl Display for Octopus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.energy == 10 {
+            write!(f, "X")
+        } else if self.energy > 10 {
+            write!(f, "x")
+        } else {
+            write!(f, "{}", self.energy)
+        }
+    }
+}
+
+pub struct StepIterator {
+    map: Map,
+}
+
+pub fn simulate(map: Map) -> StepIterator {
+    StepIterator { map }
+}
+
+impl Iterator for StepIterator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        Some(self.map.step())
+    }
+}
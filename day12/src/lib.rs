@@ -0,0 +1,231 @@
+use aoc_common::{parse::parse_lines, search::count_paths, Answers, ParseError, Solution};
+use std::str::FromStr;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("226"),
+    part_2: Some("3509"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("3761"),
+    part_2: Some("99138"),
+};
+
+/**
+ * The most caves a `Graph` can hold.
+ */
+pub const MAX_CAVES: usize = 64;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Graph::new(parse_lines(input, str::parse)?)
+    }
+
+    fn part_1(graph: &Self::Input) -> usize {
+        graph.paths_count(1)
+    }
+
+    fn part_2(graph: &Self::Input) -> usize {
+        graph.paths_count(2)
+    }
+}
+
+#[derive(Clone)]
+pub struct Path {
+    from: String,
+    to: String,
+}
+
+impl FromStr for Path {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::at(s, s, "Expected a path like `from-to`"))?;
+
+        Ok(Path {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+}
+
+/**
+ * The caves, with the caves connected to each one. Caves are referred to by their index in `names`.
+ */
+#[derive(Clone)]
+pub struct Graph {
+    names: Vec<String>,
+    connections: Vec<Vec<usize>>,
+}
+
+/**
+ * Where a path is, and what it went through to get there. `visited` has a bit set for each small
+ * cave already visited, and `revisited` is the small cave visited more than once, if any, with the
+ * number of extra visits.
+ */
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    cave: usize,
+    visited: u64,
+    revisited: Option<(usize, usize)>,
+}
+
+impl Graph {
+    /**
+     * Builds the graph from the paths, one per line of the input. Visited caves are kept as bits of
+     * a `u64`, so there can't be more than 64 caves, and paths can't go back and forth between big
+     * caves, so no two of them can be connected.
+     */
+    pub fn new(paths: Vec<Path>) -> Result<Self, ParseError> {
+        let mut graph = Graph {
+            names: vec![],
+            connections: vec![],
+        };
+
+        for (i, path) in paths.iter().enumerate() {
+            let from = graph.cave(&path.from);
+            let to = graph.cave(&path.to);
+
+            if graph.names.len() > MAX_CAVES {
+                let column = if from == MAX_CAVES {
+                    1
+                } else {
+                    path.from.len() + 2
+                };
+                return Err(ParseError::new(
+                    i + 1,
+                    column,
+                    format!("Only up to {} caves are supported", MAX_CAVES),
+                ));
+            }
+
+            if !graph.is_small(from) && !graph.is_small(to) {
+                return Err(ParseError::new(
+                    i + 1,
+                    1,
+                    "Big caves can't be connected to each other, there would be infinite paths",
+                ));
+            }
+
+            graph.connections[from].push(to);
+            graph.connections[to].push(from);
+        }
+
+        Ok(graph)
+    }
+
+    /**
+     * The index of the cave, adding it if it's new.
+     */
+    fn cave(&mut self, name: &str) -> usize {
+        self.index(name).unwrap_or_else(|| {
+            self.names.push(name.to_string());
+            self.connections.push(vec![]);
+            self.names.len() - 1
+        })
+    }
+
+    fn is_small(&self, cave: usize) -> bool {
+        self.names[cave].chars().all(char::is_lowercase)
+    }
+
+    /**
+     * Counts the paths from `start` to `end` that go through big caves any number of times, and
+     * through small caves once, except for a single small cave that can be visited up to
+     * `max_visits_for_single_small_cave` times. `start` is only visited at the beginning.
+     *
+     * A path can't go back to a state it's already been in, because it visits at least one small
+     * cave between two big ones (or there would be infinite paths), so the states form a graph
+     * without cycles and the paths from each state only need to be counted once.
+     */
+    pub fn paths_count(&self, max_visits_for_single_small_cave: usize) -> usize {
+        let (start, end) = match (self.index("start"), self.index("end")) {
+            (Some(start), Some(end)) => (start, end),
+            _ => return 0,
+        };
+
+        let initial = State {
+            cave: start,
+            visited: 1 << start,
+            revisited: None,
+        };
+
+        count_paths(
+            initial,
+            |state| {
+                self.connections[state.cave]
+                    .iter()
+                    .filter(|next| **next != start)
+                    .filter_map(|next| self.visit(state, *next, max_visits_for_single_small_cave))
+                    .collect::<Vec<_>>()
+            },
+            |state| state.cave == end,
+        )
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|cave| cave == name)
+    }
+
+    fn visit(&self, state: &State, cave: usize, max_visits: usize) -> Option<State> {
+        let mut next = State { cave, ..*state };
+
+        if !self.is_small(cave) {
+            return Some(next);
+        }
+
+        if state.visited & (1 << cave) == 0 {
+            next.visited |= 1 << cave;
+            return Some(next);
+        }
+
+        let extra_visits = match state.revisited {
+            None => 1,
+            Some((revisited, visits)) if revisited == cave => visits + 1,
+            Some(_) => return None,
+        };
+
+        if extra_visits < max_visits {
+            next.revisited = Some((cave, extra_visits));
+            Some(next)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_too_many_caves() {
+        let paths = |caves: usize| {
+            (1..caves)
+                .map(|cave| format!("start-c{}", cave))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        assert!(Day12::parse(&paths(MAX_CAVES)).is_ok());
+        let error = Day12::parse(&paths(MAX_CAVES + 1)).err().unwrap();
+        assert_eq!((error.line, error.column), (MAX_CAVES, 7));
+    }
+
+    #[test]
+    fn test_connected_big_caves() {
+        let error = Day12::parse("start-A\nA-b\nB-A\nb-end").err().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
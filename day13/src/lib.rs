@@ -0,0 +1,218 @@
+use aoc_common::{
+    geometry::BoundingBox,
+    parse::{parse_lines, parse_token},
+    Answers, Assumption, Grid, ParseError, Point, Solution,
+};
+use regex::Regex;
+use std::{fmt, str::FromStr};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("17"),
+    part_2: Some(
+        "#####\n\
+         #...#\n\
+         #...#\n\
+         #...#\n\
+         #####\n\
+         .....\n\
+         .....",
+    ),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("720"),
+    part_2: Some(
+        ".##..#..#.###..###..###...##..#..#.####.\n\
+         #..#.#..#.#..#.#..#.#..#.#..#.#..#....#.\n\
+         #..#.####.#..#.#..#.#..#.#..#.#..#...#..\n\
+         ####.#..#.###..###..###..####.#..#..#...\n\
+         #..#.#..#.#....#.#..#....#..#.#..#.#....\n\
+         #..#.#..#.#....#..#.#....#..#..##..####.",
+    ),
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Manual;
+    type Output1 = usize;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (points, instructions) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                1,
+                1,
+                "Expected points and instructions separated by an empty line",
+            )
+        })?;
+        let points: Vec<Point<usize>> = parse_lines(points, str::parse)?;
+        let paper = Paper::new(&points);
+        // The instructions start after the points and the empty line
+        let instructions_offset = input.lines().take_while(|line| line != &"").count() + 1;
+        let instructions = parse_lines(instructions, str::parse)
+            .map_err(|error| error.offset(instructions_offset))?;
+
+        if instructions.is_empty() {
+            return Err(ParseError::new(
+                instructions_offset + 1,
+                1,
+                "Missing fold instructions",
+            ));
+        }
+
+        Ok(Manual {
+            paper,
+            instructions,
+        })
+    }
+
+    fn part_1(manual: &Self::Input) -> usize {
+        manual
+            .paper
+            .clone()
+            .fold(manual.instructions[0])
+            .dots_count()
+    }
+
+    fn part_2(manual: &Self::Input) -> String {
+        let paper = manual
+            .instructions
+            .iter()
+            .fold(manual.paper.clone(), |paper, instruction| {
+                paper.fold(*instruction)
+            });
+
+        format!("{:?}", paper).trim_end().to_string()
+    }
+
+    fn lint(manual: &Self::Input) -> Vec<Assumption> {
+        let mut width = manual.paper.dots.width();
+        let mut height = manual.paper.dots.height();
+        let mut violations = vec![];
+
+        for (i, instruction) in manual.instructions.iter().enumerate() {
+            let (size, value, dimension) = match *instruction {
+                Instruction::X(value) => (&mut width, value, "wide"),
+                Instruction::Y(value) => (&mut height, value, "tall"),
+            };
+
+            if *size != 2 * value + 1 {
+                violations.push(format!(
+                    "Fold {} ({:?}) on a paper {} {}",
+                    i + 1,
+                    instruction,
+                    *size,
+                    dimension
+                ));
+            }
+
+            *size /= 2;
+        }
+
+        vec![Assumption::new(
+            "Every fold is along the middle of the paper",
+            violations,
+        )]
+    }
+}
+
+/**
+ * The transparent paper and the folding instructions that come with it.
+ */
+pub struct Manual {
+    pub paper: Paper,
+    pub instructions: Vec<Instruction>,
+}
+
+#[derive(Clone)]
+pub struct Paper {
+    dots: Grid<bool>,
+}
+
+impl Paper {
+    /**
+     * A paper just big enough to have all the given dots.
+     */
+    pub fn new(points: &[Point<usize>]) -> Self {
+        // The paper starts at (0, 0) even if there are no dots there
+        let corner = BoundingBox::of(points.iter().copied()).map_or(Point::new(0, 0), |b| b.max);
+        let mut dots = Grid::filled(corner.x + 1, corner.y + 1, false);
+
+        for point in points {
+            dots[*point] = true;
+        }
+
+        Paper { dots }
+    }
+
+    pub fn dots_count(&self) -> usize {
+        self.dots.values().filter(|dot| **dot).count()
+    }
+
+    /**
+     * Folds the bottom half up or the right half to the left. Each position of the folded paper has
+     * a dot if either of the two positions that end up on top of each other had one.
+     */
+    pub fn fold(self, instruction: Instruction) -> Paper {
+        let width = self.dots.width();
+        let height = self.dots.height();
+
+        let dots = match instruction {
+            Instruction::X(_) => Grid::from_fn(width / 2, height, |point| {
+                self.dots[point] || self.dots[Point::new(width - point.x - 1, point.y)]
+            }),
+            Instruction::Y(_) => Grid::from_fn(width, height / 2, |point| {
+                self.dots[point] || self.dots[Point::new(point.x, height - point.y - 1)]
+            }),
+        };
+
+        Paper { dots }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    X(usize),
+    Y(usize),
+}
+
+// Parsing and debugging
+
+impl fmt::Debug for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (axis, value) = match *self {
+            Instruction::X(value) => ("X", value),
+            Instruction::Y(value) => ("Y", value),
+        };
+
+        write!(f, "{}={}", axis, value)
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re = Regex::new(r"(?P<axis>[xy])=(?P<value>\d+)").unwrap();
+        let captures = re
+            .captures(s)
+            .ok_or_else(|| ParseError::at(s, s, format!("Invalid instruction `{}`", s)))?;
+        let value = parse_token(s, captures.name("value").unwrap().as_str())?;
+
+        match &captures["axis"] {
+            "x" => Ok(Instruction::X(value)),
+            _ => Ok(Instruction::Y(value)),
+        }
+    }
+}
+
+impl fmt::Debug for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dots = self.dots.map(|dot| if *dot { '#' } else { '.' });
+        write!(f, "{}", dots)
+    }
+}
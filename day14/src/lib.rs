@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answers, ParseError, Solution};
+use regex::Regex;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("1588"),
+    part_2: Some("2188189693529"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("3230"),
+    part_2: Some("3542388214529"),
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Polymer;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let template = lines
+            .next()
+            .filter(|template| !template.is_empty())
+            .ok_or_else(|| ParseError::new(1, 1, "Missing polymer template"))?
+            .to_string();
+
+        // Elements are counted through the pairs they're part of
+        if template.chars().count() < 2 {
+            return Err(ParseError::new(
+                1,
+                1,
+                "The polymer template needs at least two elements",
+            ));
+        }
+
+        // Skip empty line
+        lines.next();
+
+        // Rules start on the third line
+        let rules = parse_rules(lines).map_err(|error| error.offset(2))?;
+
+        Ok(Polymer { template, rules })
+    }
+
+    fn part_1(polymer: &Self::Input) -> usize {
+        polymer.score_after(10)
+    }
+
+    fn part_2(polymer: &Self::Input) -> usize {
+        polymer.score_after(40)
+    }
+}
+
+pub struct Polymer {
+    pub template: String,
+    pub rules: HashMap<(char, char), char>,
+}
+
+impl Polymer {
+    /**
+     * The difference between the most and least common elements after the given steps.
+     */
+    pub fn score_after(&self, steps: usize) -> usize {
+        let char_counts = PairCounter::new(&self.rules).counts(&self.template, steps);
+        let min = char_counts.values().min().unwrap();
+        let max = char_counts.values().max().unwrap();
+        max - min
+    }
+}
+
+/**
+ * Counts the elements a polymer grows into with a set of rules. The counts for each pair and number
+ * of steps are remembered, and only make sense for the rules they were counted with, which is why
+ * the counter holds on to them.
+ */
+pub struct PairCounter<'a> {
+    rules: &'a HashMap<(char, char), char>,
+    pair_counts: HashMap<((char, char), usize), HashMap<char, usize>>,
+}
+
+impl<'a> PairCounter<'a> {
+    pub fn new(rules: &'a HashMap<(char, char), char>) -> Self {
+        PairCounter {
+            rules,
+            pair_counts: HashMap::new(),
+        }
+    }
+
+    /**
+     * Forgets all the counted pairs.
+     */
+    pub fn clear(&mut self) {
+        self.pair_counts.clear();
+    }
+
+    pub fn counts(&mut self, template: &str, steps: usize) -> HashMap<char, usize> {
+        let chars: Vec<char> = template.chars().collect();
+        let mut result = HashMap::new();
+
+        for window in chars.as_slice().windows(2) {
+            for (char, count) in self.count_pair((window[0], window[1]), steps) {
+                *result.entry(char).or_insert(0) += count;
+            }
+        }
+
+        // All characters except for the first and the last get counted twice (once in each window
+        // they're part of) so we make up for that in this loop
+        for char in &chars[1..chars.len() - 1] {
+            *result.get_mut(char).unwrap() -= 1;
+        }
+
+        result
+    }
+
+    fn count_pair(&mut self, (first, second): (char, char), steps: usize) -> HashMap<char, usize> {
+        if let Some(counts) = self.pair_counts.get(&((first, second), steps)) {
+            return counts.clone();
+        }
+
+        let mut result = HashMap::new();
+
+        match self.rules.get(&(first, second)).copied() {
+            Some(insert) if steps > 0 => {
+                for (char, count) in self.count_pair((first, insert), steps - 1) {
+                    *result.entry(char).or_insert(0) += count;
+                }
+
+                for (char, count) in self.count_pair((insert, second), steps - 1) {
+                    *result.entry(char).or_insert(0) += count;
+                }
+
+                *result.get_mut(&insert).unwrap() -= 1;
+            }
+            _ => {
+                // No more steps, or simply no rule to expand the given pair
+                result.insert(first, 1);
+                *result.entry(second).or_insert(0) += 1;
+            }
+        }
+
+        self.pair_counts
+            .insert(((first, second), steps), result.clone());
+        result
+    }
+}
+
+pub fn parse_rules<'a>(
+    rules: impl Iterator<Item = &'a str>,
+) -> Result<HashMap<(char, char), char>, ParseError> {
+    let re = Regex::new(r"^(?P<given>\w\w) -> (?P<insert>\w)$").unwrap();
+    rules
+        .enumerate()
+        .map(|(i, line)| {
+            let captures = re.captures(line).ok_or_else(|| {
+                ParseError::new(
+                    i + 1,
+                    1,
+                    format!(
+                        "Expected a pair insertion rule like `AB -> C`, found `{}`",
+                        line
+                    ),
+                )
+            })?;
+
+            let mut given_chars = captures["given"].chars();
+            let given = (given_chars.next().unwrap(), given_chars.next().unwrap());
+            let insert = captures["insert"].chars().next().unwrap();
+            Ok((given, insert))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_different_rules() {
+        let polymer = Day14::parse(SAMPLE).unwrap();
+        assert_eq!(polymer.score_after(10), 1588);
+
+        // Same template, but now every pair grows with the same element
+        let rules = parse_rules(["NN -> N", "NC -> N", "CB -> N"].iter().copied()).unwrap();
+        let other = Polymer {
+            template: polymer.template.clone(),
+            rules,
+        };
+        let counts = PairCounter::new(&other.rules).counts(&other.template, 2);
+        assert_eq!(counts[&'N'], 9);
+        assert_eq!(counts[&'C'], 1);
+        assert_eq!(polymer.score_after(10), 1588);
+    }
+
+    #[test]
+    fn test_short_template() {
+        let error = Day14::parse("N\n\nNN -> C").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+}
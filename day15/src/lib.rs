@@ -0,0 +1,85 @@
+use aoc_common::{search::dijkstra, Answers, Grid, ParseError, Point, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("40"),
+    part_2: Some("315"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("583"),
+    part_2: Some("2927"),
+};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Grid<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    // Risk levels go from 1 to 9, `wrap` can't deal with a 0
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, |char| {
+            char.to_digit(10)
+                .filter(|risk| *risk > 0)
+                .map(|risk| risk as usize)
+        })
+    }
+
+    fn part_1(map: &Self::Input) -> usize {
+        shortest_path_cost(map)
+    }
+
+    fn part_2(map: &Self::Input) -> usize {
+        shortest_path_cost(&expand_map(map))
+    }
+}
+
+/**
+ * The lowest total risk of going from the top left corner to the bottom right one, where the risk
+ * of a position counts when entering it.
+ */
+pub fn shortest_path_cost(map: &Grid<usize>) -> usize {
+    let target = Point::new(map.width() - 1, map.height() - 1);
+
+    dijkstra(
+        Point::new(0, 0),
+        |position| {
+            map.neighbors(*position)
+                .map(move |neighbor| (neighbor, map[neighbor]))
+        },
+        |position| *position == target,
+    )
+    .unwrap()
+    .cost
+}
+
+/**
+ * The full map is the original one repeated 5 times in each direction. Each repetition to the right
+ * or down adds 1 to the risk levels, wrapping back to 1 after 9.
+ */
+pub fn expand_map(map: &Grid<usize>) -> Grid<usize> {
+    let (width, height) = (map.width(), map.height());
+
+    Grid::from_fn(width * 5, height * 5, |Point { x, y }| {
+        wrap(map[Point::new(x % width, y % height)] + x / width + y / height)
+    })
+}
+
+fn wrap(n: usize) -> usize {
+    (n - 1) % 9 + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_risk() {
+        let error = Day15::parse("119\n101").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
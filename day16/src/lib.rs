@@ -0,0 +1,340 @@
+use aoc_common::{Answers, ParseError, Solution};
+use bitvec::prelude::*;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("20"),
+    part_2: Some("1"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("920"),
+    part_2: Some("10185143721112"),
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Packet;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input.trim())
+    }
+
+    fn part_1(packet: &Self::Input) -> usize {
+        version_numbers_sum(packet)
+    }
+
+    fn part_2(packet: &Self::Input) -> usize {
+        process(packet)
+    }
+}
+
+type Bit = bool;
+
+#[derive(PartialEq, Debug)]
+pub enum Packet {
+    Literal(u8, usize),
+    Operator(u8, Operation, Vec<Packet>),
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Operation {
+    Sum,
+    Product,
+    Min,
+    Max,
+    Gt,
+    Lt,
+    Eq,
+}
+
+/**
+ * Decodes the hexadecimal transmission into its outermost packet. The packet parsers return `None`
+ * when the bits run out before the packet is complete.
+ */
+pub fn parse(input: &str) -> Result<Packet, ParseError> {
+    let bits = parse_bits(input)?;
+
+    parse_packet(&mut bits.iter().by_refs())
+        .map(|(packet, _)| packet)
+        .ok_or_else(|| {
+            ParseError::new(
+                1,
+                input.len() + 1,
+                "The transmission ends in the middle of a packet",
+            )
+        })
+}
+
+pub fn process(packet: &Packet) -> usize {
+    use Packet::*;
+
+    match packet {
+        Literal(_, value) => *value,
+        Operator(_, operation, packets) => {
+            use Operation::*;
+            let mut packet_values = packets.iter().map(process);
+
+            match operation {
+                Sum => packet_values.sum(),
+                Product => packet_values.product(),
+                Min => packet_values.min().unwrap(),
+                Max => packet_values.max().unwrap(),
+                Gt => {
+                    if packet_values.next().unwrap() > packet_values.next().unwrap() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                Lt => {
+                    if packet_values.next().unwrap() < packet_values.next().unwrap() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                Eq => {
+                    if packet_values.next().unwrap() == packet_values.next().unwrap() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn parse_bits(input: &str) -> Result<BitVec, ParseError> {
+    let mut bits = BitVec::new();
+
+    for (i, hexa) in input.char_indices() {
+        bits.extend(match hexa {
+            '0' => bitvec![0, 0, 0, 0],
+            '1' => bitvec![0, 0, 0, 1],
+            '2' => bitvec![0, 0, 1, 0],
+            '3' => bitvec![0, 0, 1, 1],
+            '4' => bitvec![0, 1, 0, 0],
+            '5' => bitvec![0, 1, 0, 1],
+            '6' => bitvec![0, 1, 1, 0],
+            '7' => bitvec![0, 1, 1, 1],
+            '8' => bitvec![1, 0, 0, 0],
+            '9' => bitvec![1, 0, 0, 1],
+            'A' => bitvec![1, 0, 1, 0],
+            'B' => bitvec![1, 0, 1, 1],
+            'C' => bitvec![1, 1, 0, 0],
+            'D' => bitvec![1, 1, 0, 1],
+            'E' => bitvec![1, 1, 1, 0],
+            'F' => bitvec![1, 1, 1, 1],
+            c => {
+                return Err(ParseError::new(
+                    1,
+                    i + 1,
+                    format!("Unexpected hexadecimal character `{}`", c),
+                ))
+            }
+        });
+    }
+
+    Ok(bits)
+}
+
+fn read_number<'a>(bits: &mut impl Iterator<Item = &'a Bit>, length: usize) -> Option<usize> {
+    (0..length).try_fold(0, |acc, _| Some(acc << 1 | (*bits.next()? as usize)))
+}
+
+fn parse_packet<'a>(bits: &mut impl Iterator<Item = &'a Bit>) -> Option<(Packet, usize)> {
+    use Packet::*;
+
+    let mut bits_read = 0;
+
+    let version = read_number(bits, 3)? as u8;
+    bits_read += 3;
+
+    let type_id = read_number(bits, 3)?;
+    bits_read += 3;
+
+    let (packet, more_bits_read) = match type_id {
+        4 => {
+            let (literal, more_bits_read) = consume_literal(bits)?;
+            (Literal(version, literal), more_bits_read)
+        }
+        n => {
+            let (subpackets, more_bits_read) = parse_subpackets(bits)?;
+
+            use Operation::*;
+            let operation = match n {
+                0 => Sum,
+                1 => Product,
+                2 => Min,
+                3 => Max,
+                5 => Gt,
+                6 => Lt,
+                7 => Eq,
+                _ => panic!("Invalid operation type {}", n),
+            };
+
+            (Operator(version, operation, subpackets), more_bits_read)
+        }
+    };
+
+    Some((packet, bits_read + more_bits_read))
+}
+
+fn consume_literal<'a>(bits: &mut impl Iterator<Item = &'a Bit>) -> Option<(usize, usize)> {
+    let mut result = 0;
+    let mut bits_read = 0;
+
+    while *bits.next()? {
+        result = result << 4 | read_number(bits, 4)?;
+        bits_read += 5
+    }
+    bits_read += 1;
+
+    result = result << 4 | read_number(bits, 4)?;
+    bits_read += 4;
+
+    Some((result, bits_read))
+}
+
+fn parse_subpackets<'a>(bits: &mut impl Iterator<Item = &'a Bit>) -> Option<(Vec<Packet>, usize)> {
+    let (subpackets, bits_read) = if *bits.next()? {
+        parse_subpackets_by_count(bits)?
+    } else {
+        parse_subpackets_by_length(bits)?
+    };
+
+    Some((subpackets, bits_read + 1))
+}
+
+fn parse_subpackets_by_count<'a>(
+    bits: &mut impl Iterator<Item = &'a Bit>,
+) -> Option<(Vec<Packet>, usize)> {
+    let count = read_number(bits, 11)?;
+
+    let mut bits_read = 0;
+    let mut packets = vec![];
+
+    for _ in 0..count {
+        let (packet, more_bits_read) = parse_packet(bits)?;
+        packets.push(packet);
+        bits_read += more_bits_read;
+    }
+
+    Some((packets, bits_read + 11))
+}
+
+fn parse_subpackets_by_length<'a>(
+    bits: &mut impl Iterator<Item = &'a Bit>,
+) -> Option<(Vec<Packet>, usize)> {
+    let length = read_number(bits, 15)?;
+
+    let mut bits_read = 0;
+    let mut packets = vec![];
+
+    while bits_read < length {
+        let (packet, more_bits_read) = parse_packet(bits)?;
+        packets.push(packet);
+        bits_read += more_bits_read;
+    }
+
+    Some((packets, bits_read + 15))
+}
+
+pub fn version_numbers_sum(packet: &Packet) -> usize {
+    use Packet::*;
+
+    match packet {
+        Literal(version, _) => *version as usize,
+        Operator(version, _, subpackets) => {
+            let sum: usize = subpackets.iter().map(version_numbers_sum).sum();
+
+            (*version as usize) + sum
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_literal_packet() {
+        assert_eq!(parse("D2FE28").unwrap(), Packet::Literal(6, 2021));
+    }
+
+    #[test]
+    fn test_parse_operator_with_bit_length() {
+        assert_eq!(
+            parse("38006F45291200").unwrap(),
+            Packet::Operator(
+                1,
+                Operation::Lt,
+                vec![Packet::Literal(6, 10), Packet::Literal(2, 20)]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_operator_with_subpacket_count() {
+        assert_eq!(
+            parse("EE00D40C823060").unwrap(),
+            Packet::Operator(
+                7,
+                Operation::Max,
+                vec![
+                    Packet::Literal(2, 1),
+                    Packet::Literal(4, 2),
+                    Packet::Literal(1, 3),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_added_up_version_numbers() {
+        assert_eq!(
+            version_numbers_sum(&parse("8A004A801A8002F478").unwrap()),
+            16
+        );
+        assert_eq!(
+            version_numbers_sum(&parse("620080001611562C8802118E34").unwrap()),
+            12
+        );
+        assert_eq!(
+            version_numbers_sum(&parse("C0015000016115A2E0802F182340").unwrap()),
+            23
+        );
+        assert_eq!(
+            version_numbers_sum(&parse("A0016C880162017C3686B18A3D4780").unwrap()),
+            31
+        );
+    }
+
+    #[test]
+    fn test_packet_processing() {
+        assert_eq!(process(&parse("C200B40A82").unwrap()), 3);
+        assert_eq!(process(&parse("04005AC33890").unwrap()), 54);
+        assert_eq!(process(&parse("880086C3E88112").unwrap()), 7);
+        assert_eq!(process(&parse("CE00C43D881120").unwrap()), 9);
+        assert_eq!(process(&parse("D8005AC2A8F0").unwrap()), 1);
+        assert_eq!(process(&parse("F600BC2D8F").unwrap()), 0);
+        assert_eq!(process(&parse("9C005AC2F8F0").unwrap()), 0);
+        assert_eq!(process(&parse("9C0141080250320F1802104A08").unwrap()), 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("D2GE28").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        // A literal packet cut right after its header
+        assert!(parse("D2").is_err());
+    }
+}
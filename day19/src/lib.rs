@@ -0,0 +1,381 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, Sub},
+};
+
+use aoc_common::{parse::parse_token, Answers, ParseError, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("79"),
+    part_2: Some("3621"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: None,
+    part_2: None,
+};
+
+/**
+ * --- Day 19: Beacon Scanner ---
+ *
+ * The input is a list of reports from scanners dropped in the ocean. Each scanner reports the
+ * positions of the beacons it can see (up to 1000 units away in each axis), relative to itself.
+ * But scanners don't know where they are or which way they are facing: each one could be rotated
+ * in any of the 24 ways a cube can be rotated, so their reports use different axes. Scanners that
+ * see the same region share at least 12 beacons, and that's enough to find how one scanner is
+ * placed relative to the other.
+ *
+ * Part 1 asks for the number of distinct beacons once all the reports are combined in a single
+ * map, and part 2 for the largest Manhattan distance between any two scanners in that map.
+ *
+ * The map is assembled starting from the first scanner, which defines the axes and the origin.
+ * Every time a scanner gets placed, the ones that aren't placed yet are compared against it. For
+ * each of the 24 rotations, the unplaced scanner's beacons are rotated and subtracted from each of
+ * the placed scanner's beacons. If both scanners see the same 12 beacons, the same difference
+ * shows up (at least) 12 times, and that difference is the position of the unplaced scanner. That
+ * is done in `align`, and `assemble` repeats it until every scanner is placed. That's the slow
+ * part, and both parts need the whole map, so it's assembled once while parsing. A scanner that
+ * can't be placed is reported as an error at its header.
+ */
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = BeaconMap;
+    type Output1 = usize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        assemble(&parse_scanners(input)?)
+    }
+
+    fn part_1(map: &Self::Input) -> usize {
+        map.beacons.len()
+    }
+
+    fn part_2(map: &Self::Input) -> isize {
+        map.scanners
+            .iter()
+            .flat_map(|a| map.scanners.iter().map(move |b| a.manhattan_distance(*b)))
+            .max()
+            .unwrap()
+    }
+}
+
+/**
+ * How many beacons two scanners need to have in common to be sure they see the same region.
+ */
+pub const MIN_OVERLAP: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Vector {
+    pub fn new(x: isize, y: isize, z: isize) -> Self {
+        Vector { x, y, z }
+    }
+
+    pub fn manhattan_distance(self, other: Vector) -> isize {
+        let difference = self - other;
+        difference.x.abs() + difference.y.abs() + difference.z.abs()
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Vector::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Vector::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/**
+ * A rotation as a 3x3 matrix. The 24 orientations of a scanner are the matrices that pick each axis
+ * from a different input axis, with any sign, as long as the result isn't a mirror image (which is
+ * the same as having a determinant of 1).
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation([[isize; 3]; 3]);
+
+impl Rotation {
+    pub fn all() -> Vec<Rotation> {
+        let permutations = [
+            [0, 1, 2],
+            [0, 2, 1],
+            [1, 0, 2],
+            [1, 2, 0],
+            [2, 0, 1],
+            [2, 1, 0],
+        ];
+
+        let mut rotations = vec![];
+        for axes in permutations {
+            for signs in 0..8 {
+                let mut matrix = [[0; 3]; 3];
+                for (row, axis) in axes.iter().enumerate() {
+                    matrix[row][*axis] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                }
+
+                let rotation = Rotation(matrix);
+                if rotation.determinant() == 1 {
+                    rotations.push(rotation);
+                }
+            }
+        }
+
+        rotations
+    }
+
+    fn determinant(&self) -> isize {
+        let m = self.0;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    pub fn apply(&self, vector: Vector) -> Vector {
+        let row = |r: [isize; 3]| r[0] * vector.x + r[1] * vector.y + r[2] * vector.z;
+        Vector::new(row(self.0[0]), row(self.0[1]), row(self.0[2]))
+    }
+}
+
+/**
+ * The beacons seen by a single scanner, relative to it and in its own orientation. `line` is where
+ * its report starts, to point at the scanner when it doesn't fit in the map.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scanner {
+    pub line: usize,
+    pub beacons: Vec<Vector>,
+}
+
+/**
+ * Every beacon and scanner position, in the coordinates of the first scanner.
+ */
+#[derive(Debug)]
+pub struct BeaconMap {
+    pub beacons: HashSet<Vector>,
+    pub scanners: Vec<Vector>,
+}
+
+/**
+ * Each report starts with a `--- scanner N ---` header followed by one `x,y,z` line per beacon.
+ * Reports are separated by blank lines.
+ */
+pub fn parse_scanners(input: &str) -> Result<Vec<Scanner>, ParseError> {
+    let mut scanners = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let result = if line.starts_with("--- scanner ") && line.ends_with(" ---") {
+            scanners.push(Scanner {
+                line: i + 1,
+                beacons: vec![],
+            });
+            Ok(())
+        } else if line.is_empty() {
+            Ok(())
+        } else {
+            match scanners.last_mut() {
+                Some(scanner) => parse_beacon(line).map(|beacon| scanner.beacons.push(beacon)),
+                None => Err(ParseError::new(
+                    1,
+                    1,
+                    "Expected a `--- scanner N ---` header",
+                )),
+            }
+        };
+
+        result.map_err(|error| error.offset(i))?;
+    }
+
+    if scanners.is_empty() {
+        return Err(ParseError::new(1, 1, "There are no scanners"));
+    }
+
+    Ok(scanners)
+}
+
+fn parse_beacon(line: &str) -> Result<Vector, ParseError> {
+    let coordinates = line
+        .split(',')
+        .map(|token| parse_token(line, token))
+        .collect::<Result<Vec<isize>, _>>()?;
+
+    match coordinates[..] {
+        [x, y, z] => Ok(Vector::new(x, y, z)),
+        _ => Err(ParseError::at(line, line, "Expected a beacon like `x,y,z`")),
+    }
+}
+
+/**
+ * Tries to place `scanner` relative to beacons that are already in the map's coordinates. Returns
+ * the position of the scanner and its beacons moved to the map's coordinates.
+ */
+pub fn align(known: &[Vector], scanner: &Scanner) -> Option<(Vector, Vec<Vector>)> {
+    Rotation::all().iter().find_map(|rotation| {
+        let rotated: Vec<Vector> = scanner
+            .beacons
+            .iter()
+            .map(|beacon| rotation.apply(*beacon))
+            .collect();
+
+        let mut offsets: HashMap<Vector, usize> = HashMap::new();
+        for a in known {
+            for b in &rotated {
+                *offsets.entry(*a - *b).or_default() += 1;
+            }
+        }
+
+        offsets
+            .into_iter()
+            .find(|(_, count)| *count >= MIN_OVERLAP)
+            .map(|(position, _)| {
+                let beacons = rotated.iter().map(|beacon| *beacon + position).collect();
+                (position, beacons)
+            })
+    })
+}
+
+/**
+ * Places every scanner, starting from the first one, which there has to be. Fails at the first
+ * scanner that doesn't overlap with any of the placed ones once no more can be placed.
+ */
+pub fn assemble(scanners: &[Scanner]) -> Result<BeaconMap, ParseError> {
+    let mut placed: Vec<Option<(Vector, Vec<Vector>)>> = vec![None; scanners.len()];
+    placed[0] = Some((Vector::new(0, 0, 0), scanners[0].beacons.clone()));
+    let mut to_visit = vec![0];
+
+    while let Some(reference) = to_visit.pop() {
+        let known = placed[reference].as_ref().unwrap().1.clone();
+
+        for (i, scanner) in scanners.iter().enumerate() {
+            if placed[i].is_none() {
+                if let Some(alignment) = align(&known, scanner) {
+                    placed[i] = Some(alignment);
+                    to_visit.push(i);
+                }
+            }
+        }
+    }
+
+    let placed: Vec<(Vector, Vec<Vector>)> = placed
+        .into_iter()
+        .zip(scanners)
+        .map(|(alignment, scanner)| {
+            alignment.ok_or_else(|| {
+                ParseError::new(
+                    scanner.line,
+                    1,
+                    format!(
+                        "The scanner doesn't share {} beacons with any of the placed ones",
+                        MIN_OVERLAP
+                    ),
+                )
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(BeaconMap {
+        beacons: placed
+            .iter()
+            .flat_map(|(_, beacons)| beacons.iter().copied())
+            .collect(),
+        scanners: placed.iter().map(|(position, _)| *position).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        let rotations = Rotation::all();
+        assert_eq!(rotations.len(), 24);
+
+        let vector = Vector::new(1, 2, 3);
+        let rotated: HashSet<Vector> = rotations.iter().map(|r| r.apply(vector)).collect();
+        assert_eq!(rotated.len(), 24);
+        assert!(rotated.contains(&vector));
+        assert!(!rotated.contains(&Vector::new(-1, 2, 3)));
+    }
+
+    #[test]
+    fn test_align() {
+        let known: Vec<Vector> = (0..12).map(|i| Vector::new(i, i * i, 7 - i)).collect();
+        let rotation = Rotation::all()[5];
+        let position = Vector::new(1000, -20, 300);
+
+        // The beacons seen from `position`, in a rotated frame
+        let inverse = Rotation::all()
+            .into_iter()
+            .find(|inverse| {
+                inverse.apply(rotation.apply(Vector::new(1, 2, 3))) == Vector::new(1, 2, 3)
+            })
+            .unwrap();
+        let scanner = Scanner {
+            line: 1,
+            beacons: known
+                .iter()
+                .map(|beacon| inverse.apply(*beacon - position))
+                .collect(),
+        };
+
+        let (found, beacons) = align(&known, &scanner).unwrap();
+        assert_eq!(found, position);
+        assert_eq!(beacons, known);
+
+        let too_few = Scanner {
+            line: 1,
+            beacons: scanner.beacons[1..].to_vec(),
+        };
+        assert_eq!(align(&known, &too_few), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_scanners("1,2,3").unwrap_err().line, 1);
+
+        let error = parse_scanners("--- scanner 0 ---\n1,2,3\n4,x,6").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+
+        let error = parse_scanners("--- scanner 0 ---\n1,2").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_sample() {
+        let scanners = parse_scanners(SAMPLE).unwrap();
+        assert_eq!(scanners.len(), 5);
+        assert_eq!(scanners[1].line, 28);
+
+        let map = Day19::parse(SAMPLE).unwrap();
+        assert_eq!(Day19::part_1(&map), 79);
+        assert_eq!(map.scanners[1], Vector::new(68, -1246, -43));
+        assert_eq!(map.scanners[2], Vector::new(1105, -1205, 1229));
+        assert_eq!(map.scanners[3], Vector::new(-92, -2380, -20));
+        assert_eq!(map.scanners[4], Vector::new(-20, -1133, 1061));
+        assert_eq!(Day19::part_2(&map), 3621);
+
+        // Scanners 2 and 4 are only placed through scanner 1, so without it the map can't grow past
+        // scanners 0 and 3
+        let mut separate = scanners.clone();
+        separate.remove(1);
+        let error = assemble(&separate).unwrap_err();
+        assert_eq!(error.line, scanners[2].line);
+    }
+}
@@ -0,0 +1,5 @@
+use day19::{Day19, INPUT, SAMPLE};
+
+fn main() {
+    aoc_common::solution::run::<Day19>(SAMPLE, INPUT);
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use aoc_common::{Answers, Grid, ParseError, Point, Solution};
+use bitvec::prelude::*;
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("35"),
+    part_2: Some("3351"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("5503"),
+    part_2: Some("19156"),
+};
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = TrenchMap;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = input.splitn(3, '\n');
+        let algorithm: BitVec = parse_pixels(sections.next().unwrap_or_default())?
+            .into_iter()
+            .collect();
+
+        if algorithm.len() != 512 {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!(
+                    "The image enhancement algorithm must have 512 pixels, found {}",
+                    algorithm.len()
+                ),
+            ));
+        }
+
+        sections.next();
+        // The image starts on the third line
+        let image =
+            parse_image(sections.next().unwrap_or_default()).map_err(|error| error.offset(2))?;
+
+        Ok(TrenchMap { algorithm, image })
+    }
+
+    fn part_1(trench_map: &Self::Input) -> usize {
+        trench_map.light_pixels_after(2)
+    }
+
+    fn part_2(trench_map: &Self::Input) -> usize {
+        trench_map.light_pixels_after(50)
+    }
+}
+
+/**
+ * The image enhancement algorithm and the input image it applies to.
+ */
+pub struct TrenchMap {
+    pub algorithm: BitVec,
+    pub image: Image,
+}
+
+impl TrenchMap {
+    pub fn light_pixels_after(&self, steps: usize) -> usize {
+        let mut image = self.image.clone();
+
+        for _ in 0..steps {
+            image = image.decompress_step(&self.algorithm);
+        }
+
+        image.light_pixels_count()
+    }
+}
+
+/**
+ * The pixels of the image that have been processed so far, and the value of all the pixels outside
+ * of them, which are infinite but all the same.
+ */
+#[derive(Clone)]
+pub struct Image {
+    pixels: Grid<bool>,
+    empty_pixel_value: bool,
+}
+
+impl Image {
+    fn new(pixels: Grid<bool>, empty_pixel_value: bool) -> Self {
+        Image {
+            pixels,
+            empty_pixel_value,
+        }
+    }
+
+    pub fn light_pixels_count(&self) -> usize {
+        self.pixels.values().filter(|pixel| **pixel).count()
+    }
+
+    /**
+     * The pixel at `point`, which can be outside of the processed pixels (even to the left or
+     * above them).
+     */
+    fn pixel(&self, point: Point<isize>) -> bool {
+        point
+            .try_cast()
+            .and_then(|point| self.pixels.get(point))
+            .copied()
+            .unwrap_or(self.empty_pixel_value)
+    }
+}
+
+pub fn parse_image(input: &str) -> Result<Image, ParseError> {
+    let pixels = Grid::parse(input, parse_pixel)?;
+    Ok(Image::new(pixels, false))
+}
+
+/**
+ * Light pixels are `#` and dark ones are `.`, both in the algorithm and in the image.
+ */
+fn parse_pixel(char: char) -> Option<bool> {
+    match char {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn parse_pixels(line: &str) -> Result<Vec<bool>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            parse_pixel(c)
+                .ok_or_else(|| ParseError::new(1, i + 1, format!("Invalid pixel `{}`", c)))
+        })
+        .collect()
+}
+
+impl Image {
+    /**
+     * Applies the algorithm once. Only the pixels next to the processed ones can change in a way
+     * that is different from the rest of the infinite image, so the image grows by one pixel in
+     * each direction.
+     */
+    pub fn decompress_step(&self, algorithm: &BitVec) -> Image {
+        let width = self.pixels.width() + 2;
+        let height = self.pixels.height() + 2;
+
+        let new_pixels = Grid::from_fn(width, height, |position| {
+            // Coordinates in the current image, which starts one pixel to the right and down
+            let center = position.try_cast::<isize>().unwrap() - Point::new(1, 1);
+            let mut algorithm_key = 0;
+
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    let pixel = self.pixel(center + Point::new(x, y));
+                    algorithm_key = (algorithm_key << 1) | pixel as usize;
+                }
+            }
+
+            algorithm[algorithm_key]
+        });
+
+        let empty_pixel_value = if self.empty_pixel_value {
+            *algorithm.last().unwrap()
+        } else {
+            *algorithm.first().unwrap()
+        };
+
+        Image::new(new_pixels, empty_pixel_value)
+    }
+}
+
+impl fmt::Debug for Image {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pixels = self.pixels.map(|pixel| if *pixel { '#' } else { '.' });
+        write!(f, "{}", pixels)
+    }
+}
@@ -0,0 +1,253 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse::parse_token, Answers, ParseError, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("739785"),
+    part_2: Some("444356092776315"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: Some("1073709"),
+    part_2: Some("148747830493442"),
+};
+
+/**
+ * --- Day 21: Dirac Dice ---
+ *
+ * Two players move around a circular track of 10 spaces, and score the number of the space they
+ * land on. For part 1 they roll a deterministic 100-sided die that always rolls 1, 2, 3... in
+ * order, and the game is played until someone reaches 1000 points. `DeterministicGame` is an
+ * iterator over the scores after each turn, and the answer combines the losing score with the
+ * number of rolls.
+ *
+ * For part 2 the die has 3 sides, and every roll splits the universe in 3, one for each outcome.
+ * The game ends at 21 points, and the answer is the number of universes where the player that wins
+ * in more universes wins. There are far too many universes to play them one by one, but very few
+ * distinct game states: positions and scores of both players, and whose turn it is. Swapping the
+ * players after each turn removes the last one, since the player about to move is always the
+ * "current" one. `QuantumGame` counts the wins from a state by adding up the wins from the 7
+ * possible sums of three rolls (weighted by how many of the 27 universes roll that sum), and
+ * remembers the counts of every state it has already seen.
+ */
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = (usize, usize);
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_starting_positions(input)
+    }
+
+    fn part_1((player_1, player_2): &Self::Input) -> usize {
+        let mut game = DeterministicGame::new(*player_1, *player_2);
+        let (player_1_final_score, player_2_final_score) = game.by_ref().last().unwrap();
+        [player_1_final_score, player_2_final_score]
+            .iter()
+            .min()
+            .unwrap()
+            * game.total_rolls()
+    }
+
+    fn part_2((player_1, player_2): &Self::Input) -> usize {
+        let (player_1_wins, player_2_wins) = QuantumGame::new().wins(*player_1, *player_2);
+        player_1_wins.max(player_2_wins)
+    }
+}
+
+pub fn parse_starting_positions(s: &str) -> Result<(usize, usize), ParseError> {
+    let mut lines = s.lines();
+    let mut next_position = |line_number: usize| {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(line_number, 1, "Missing starting position"))?;
+        let position = line.rsplit(' ').next().unwrap_or_default();
+
+        match parse_token(line, position) {
+            Ok(position @ 1..=10) => Ok(position),
+            Ok(_) => Err(ParseError::at(
+                line,
+                position,
+                "Starting positions go from 1 to 10",
+            )),
+            Err(error) => Err(error),
+        }
+        .map_err(|error| error.offset(line_number - 1))
+    };
+
+    Ok((next_position(1)?, next_position(2)?))
+}
+
+pub struct DeterministicGame {
+    player_1_position: usize,
+    player_2_position: usize,
+    player_1_score: usize,
+    player_2_score: usize,
+    dice: DeterministicDice,
+}
+
+impl DeterministicGame {
+    pub fn new(player_1_position: usize, player_2_position: usize) -> Self {
+        DeterministicGame {
+            player_1_position: player_1_position - 1,
+            player_2_position: player_2_position - 1,
+            player_1_score: 0,
+            player_2_score: 0,
+            dice: DeterministicDice::new(),
+        }
+    }
+
+    pub fn total_rolls(&self) -> usize {
+        self.dice.roll
+    }
+}
+
+impl Iterator for DeterministicGame {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.player_1_score >= 1000 || self.player_2_score >= 1000 {
+            return None;
+        }
+
+        let player_1_roll: usize = self.dice.by_ref().take(3).sum();
+        self.player_1_position = (self.player_1_position + player_1_roll) % 10;
+        self.player_1_score += self.player_1_position + 1;
+
+        if self.player_1_score >= 1000 {
+            return Some((self.player_1_score, self.player_2_score));
+        }
+
+        let player_2_roll: usize = self.dice.by_ref().take(3).sum();
+        self.player_2_position = (self.player_2_position + player_2_roll) % 10;
+        self.player_2_score += self.player_2_position + 1;
+        Some((self.player_1_score, self.player_2_score))
+    }
+}
+
+pub struct DeterministicDice {
+    roll: usize,
+}
+
+impl DeterministicDice {
+    fn new() -> Self {
+        DeterministicDice { roll: 0 }
+    }
+}
+
+impl Iterator for DeterministicDice {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let roll = self.roll % 100 + 1;
+        self.roll += 1;
+        Some(roll)
+    }
+}
+
+/**
+ * How many of the 27 universes created by three rolls of the quantum die end up with each sum.
+ */
+const QUANTUM_ROLLS: [(usize, usize); 7] = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+const QUANTUM_TARGET_SCORE: usize = 21;
+
+/**
+ * A game in progress, from the point of view of the player that moves next. Positions are 0-based
+ * like in `DeterministicGame`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct QuantumState {
+    current_position: usize,
+    current_score: usize,
+    other_position: usize,
+    other_score: usize,
+}
+
+/**
+ * Counts the universes won by each player with the quantum die. The counts of every state are
+ * memoized, so a single instance can be reused for several games.
+ */
+pub struct QuantumGame {
+    wins: HashMap<QuantumState, (usize, usize)>,
+}
+
+impl QuantumGame {
+    pub fn new() -> Self {
+        QuantumGame {
+            wins: HashMap::new(),
+        }
+    }
+
+    /**
+     * The number of universes where player 1 and player 2 win, given their starting positions.
+     */
+    pub fn wins(&mut self, player_1_position: usize, player_2_position: usize) -> (usize, usize) {
+        self.count(QuantumState {
+            current_position: player_1_position - 1,
+            current_score: 0,
+            other_position: player_2_position - 1,
+            other_score: 0,
+        })
+    }
+
+    /**
+     * The universes won by the current player and by the other player from `state`.
+     */
+    fn count(&mut self, state: QuantumState) -> (usize, usize) {
+        if let Some(wins) = self.wins.get(&state) {
+            return *wins;
+        }
+
+        let mut current_wins = 0;
+        let mut other_wins = 0;
+
+        for (roll, universes) in QUANTUM_ROLLS {
+            let position = (state.current_position + roll) % 10;
+            let score = state.current_score + position + 1;
+
+            if score >= QUANTUM_TARGET_SCORE {
+                current_wins += universes;
+            } else {
+                let (next_wins, next_other_wins) = self.count(QuantumState {
+                    current_position: state.other_position,
+                    current_score: state.other_score,
+                    other_position: position,
+                    other_score: score,
+                });
+                current_wins += universes * next_other_wins;
+                other_wins += universes * next_wins;
+            }
+        }
+
+        self.wins.insert(state, (current_wins, other_wins));
+        (current_wins, other_wins)
+    }
+}
+
+impl Default for QuantumGame {
+    fn default() -> Self {
+        QuantumGame::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample() {
+        let starting_positions = Day21::parse(SAMPLE).unwrap();
+        assert_eq!(starting_positions, (4, 8));
+        assert_eq!(Day21::part_1(&starting_positions), 739785);
+        assert_eq!(
+            QuantumGame::new().wins(4, 8),
+            (444356092776315, 341960390180808)
+        );
+    }
+}
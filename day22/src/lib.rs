@@ -0,0 +1,279 @@
+use std::str::FromStr;
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answers, ParseError, Solution,
+};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("39"),
+    part_2: Some("39"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: None,
+    part_2: None,
+};
+
+/**
+ * --- Day 22: Reactor Reboot ---
+ *
+ * The reactor is a 3D grid of cubes that start turned off. The input is a list of reboot steps,
+ * each turning on or off every cube inside a cuboid. Part 1 asks how many cubes are on after the
+ * steps, counting only the initialization region (-50..50 in each axis). Part 2 asks the same for
+ * the whole reactor.
+ *
+ * Part 1 is small enough to keep every cube in a set, but the cuboids of part 2 span hundreds of
+ * thousands of cubes in each axis. So instead of the cubes, `Reactor` keeps a list of cuboids with
+ * a sign, and the cubes that are on are the sum of their signed volumes (inclusion–exclusion).
+ * When a step is applied, each cuboid already in the list that intersects it gets the intersection
+ * added with the opposite sign, which cancels out the cubes of the step that were counted before.
+ * Then, if the step turns cubes on, the step's cuboid is added with a positive sign. Turning cubes
+ * off is simply not adding it back.
+ *
+ * Part 1 reuses the same approach, clipping every step to the initialization region first.
+ */
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Step>;
+    type Output1 = isize;
+    type Output2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(steps: &Self::Input) -> isize {
+        let region = Cuboid::new([-50, -50, -50], [50, 50, 50]);
+        let mut reactor = Reactor::new();
+
+        for step in steps {
+            if let Some(cuboid) = step.cuboid.intersection(&region) {
+                reactor.apply(&Step { cuboid, ..*step });
+            }
+        }
+
+        reactor.cubes_on()
+    }
+
+    fn part_2(steps: &Self::Input) -> isize {
+        let mut reactor = Reactor::new();
+
+        for step in steps {
+            reactor.apply(step);
+        }
+
+        reactor.cubes_on()
+    }
+}
+
+/**
+ * A cuboid of cubes, with both corners included, so a single cube has the same `min` and `max`.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cuboid {
+    pub min: [isize; 3],
+    pub max: [isize; 3],
+}
+
+impl Cuboid {
+    pub fn new(min: [isize; 3], max: [isize; 3]) -> Self {
+        Cuboid { min, max }
+    }
+
+    pub fn volume(&self) -> isize {
+        (0..3)
+            .map(|axis| self.max[axis] - self.min[axis] + 1)
+            .product()
+    }
+
+    /**
+     * The cubes in both cuboids, if there are any.
+     */
+    pub fn intersection(&self, other: &Cuboid) -> Option<Cuboid> {
+        let mut intersection = *self;
+
+        for axis in 0..3 {
+            intersection.min[axis] = self.min[axis].max(other.min[axis]);
+            intersection.max[axis] = self.max[axis].min(other.max[axis]);
+
+            if intersection.min[axis] > intersection.max[axis] {
+                return None;
+            }
+        }
+
+        Some(intersection)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub on: bool,
+    pub cuboid: Cuboid,
+}
+
+/**
+ * Steps look like `on x=10..12,y=10..12,z=10..12`. The axes have to be in that order, and the
+ * lower bound of each range can't be over the upper one.
+ */
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (state, ranges) = s.split_once(' ').ok_or_else(|| {
+            ParseError::at(s, s, "Expected a step like `on x=1..2,y=1..2,z=1..2`")
+        })?;
+        let on = match state {
+            "on" => true,
+            "off" => false,
+            _ => return Err(ParseError::at(s, state, "Expected `on` or `off`")),
+        };
+
+        let ranges: Vec<&str> = ranges.split(',').collect();
+        if ranges.len() != 3 {
+            return Err(ParseError::at(
+                s,
+                ranges[0],
+                "Expected ranges for x, y and z",
+            ));
+        }
+
+        let mut cuboid = Cuboid::new([0; 3], [0; 3]);
+        for (axis, (range, name)) in ranges.iter().zip(["x=", "y=", "z="]).enumerate() {
+            let (min, max) = range
+                .strip_prefix(name)
+                .and_then(|range| range.split_once(".."))
+                .ok_or_else(|| {
+                    ParseError::at(s, range, format!("Expected a range like `{}1..2`", name))
+                })?;
+
+            cuboid.min[axis] = parse_token(s, min)?;
+            cuboid.max[axis] = parse_token(s, max)?;
+
+            if cuboid.min[axis] > cuboid.max[axis] {
+                return Err(ParseError::at(s, range, "The range is reversed"));
+            }
+        }
+
+        Ok(Step { on, cuboid })
+    }
+}
+
+/**
+ * The cubes that are on, as cuboids with a sign: positive ones add their cubes, and negative ones
+ * remove cubes that were counted twice (or that were turned off).
+ */
+pub struct Reactor {
+    cuboids: Vec<(Cuboid, isize)>,
+}
+
+impl Reactor {
+    pub fn new() -> Self {
+        Reactor { cuboids: vec![] }
+    }
+
+    pub fn apply(&mut self, step: &Step) {
+        let overlaps: Vec<(Cuboid, isize)> = self
+            .cuboids
+            .iter()
+            .filter_map(|(cuboid, sign)| {
+                cuboid
+                    .intersection(&step.cuboid)
+                    .map(|intersection| (intersection, -sign))
+            })
+            .collect();
+        self.cuboids.extend(overlaps);
+
+        if step.on {
+            self.cuboids.push((step.cuboid, 1));
+        }
+    }
+
+    pub fn cubes_on(&self) -> isize {
+        self.cuboids
+            .iter()
+            .map(|(cuboid, sign)| sign * cuboid.volume())
+            .sum()
+    }
+}
+
+impl Default for Reactor {
+    fn default() -> Self {
+        Reactor::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let a = Cuboid::new([10, 10, 10], [12, 12, 12]);
+        let b = Cuboid::new([11, 11, 11], [13, 13, 13]);
+        assert_eq!(a.volume(), 27);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Cuboid::new([11, 11, 11], [12, 12, 12]))
+        );
+        assert_eq!(a.intersection(&Cuboid::new([13, 0, 0], [20, 20, 20])), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = "on x=1..2,y=1..2".parse::<Step>().unwrap_err();
+        assert_eq!(error.column, 4);
+
+        let error = "on x=1..2,y=1..x,z=1..2".parse::<Step>().unwrap_err();
+        assert_eq!(error.column, 16);
+
+        assert_eq!(
+            "toggle x=1..2,y=1..2,z=1..2"
+                .parse::<Step>()
+                .unwrap_err()
+                .column,
+            1
+        );
+        assert!("on x=2..1,y=1..2,z=1..2".parse::<Step>().is_err());
+    }
+
+    #[test]
+    fn test_reactor_matches_cube_by_cube() {
+        let steps = Day22::parse(SAMPLE).unwrap();
+        let mut cubes = HashSet::new();
+
+        for step in &steps {
+            let Cuboid { min, max } = step.cuboid;
+            for x in min[0]..=max[0] {
+                for y in min[1]..=max[1] {
+                    for z in min[2]..=max[2] {
+                        if step.on {
+                            cubes.insert((x, y, z));
+                        } else {
+                            cubes.remove(&(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+
+        assert_eq!(Day22::part_2(&steps), cubes.len() as isize);
+        assert_eq!(Day22::part_1(&steps), 39);
+    }
+
+    #[test]
+    fn test_larger_samples() {
+        let steps = Day22::parse(include_str!("../larger_sample.txt")).unwrap();
+        assert_eq!(Day22::part_1(&steps), 590784);
+
+        let steps = Day22::parse(include_str!("../reboot_sample.txt")).unwrap();
+        assert_eq!(Day22::part_1(&steps), 474140);
+        assert_eq!(Day22::part_2(&steps), 2758514936282235);
+    }
+}
@@ -0,0 +1,5 @@
+use day22::{Day22, INPUT, SAMPLE};
+
+fn main() {
+    aoc_common::solution::run::<Day22>(SAMPLE, INPUT);
+}
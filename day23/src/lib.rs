@@ -0,0 +1,495 @@
+use std::fmt;
+
+use aoc_common::{search::a_star, Answers, ParseError, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("12521"),
+    part_2: Some("44169"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: None,
+    part_2: None,
+};
+
+/**
+ * --- Day 23: Amphipod ---
+ *
+ * Four types of amphipods (A, B, C and D) start in the wrong rooms of a burrow, and have to be
+ * organized with each type in its own room (A in the left-most one, D in the right-most one). They
+ * can only move from a room to the hallway (without stopping right outside a room), and from the
+ * hallway to their own room, as long as it only has amphipods of the right type. Each step costs
+ * 1, 10, 100 or 1000 energy depending on the type. The problem asks for the least energy needed to
+ * organize them, with rooms 2 amphipods deep for part 1, and with two extra rows inserted in the
+ * middle (rooms 4 amphipods deep) for part 2.
+ *
+ * Each arrangement of the burrow is a node of a graph, and the moves allowed by the rules are the
+ * edges, weighted with the energy they need. So the answer is the cost of the shortest path to the
+ * organized burrow, which is found with A*. The estimate of the remaining energy is what it would
+ * take to move every amphipod to the door of its room ignoring all the others, which is never more
+ * than the actual energy. `Burrow` stores the hallway and all the rooms as a single list of cells,
+ * so both parts use the same code regardless of the depth of the rooms.
+ */
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Burrow;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(burrow: &Self::Input) -> usize {
+        least_energy(burrow).expect("The amphipods can't be organized")
+    }
+
+    fn part_2(burrow: &Self::Input) -> usize {
+        least_energy(&burrow.unfold()).expect("The amphipods can't be organized")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Amphipod {
+    Amber,
+    Bronze,
+    Copper,
+    Desert,
+}
+
+impl Amphipod {
+    pub fn from_char(char: char) -> Option<Self> {
+        match char {
+            'A' => Some(Amphipod::Amber),
+            'B' => Some(Amphipod::Bronze),
+            'C' => Some(Amphipod::Copper),
+            'D' => Some(Amphipod::Desert),
+            _ => None,
+        }
+    }
+
+    pub fn as_char(self) -> char {
+        match self {
+            Amphipod::Amber => 'A',
+            Amphipod::Bronze => 'B',
+            Amphipod::Copper => 'C',
+            Amphipod::Desert => 'D',
+        }
+    }
+
+    pub fn energy(self) -> usize {
+        match self {
+            Amphipod::Amber => 1,
+            Amphipod::Bronze => 10,
+            Amphipod::Copper => 100,
+            Amphipod::Desert => 1000,
+        }
+    }
+
+    /**
+     * The index of the room where this type of amphipod belongs, from left to right.
+     */
+    pub fn room(self) -> usize {
+        self as usize
+    }
+}
+
+pub const HALLWAY_LENGTH: usize = 11;
+pub const ROOMS: usize = 4;
+
+/**
+ * The type of amphipod that belongs in each room.
+ */
+const ROOM_TYPES: [Amphipod; ROOMS] = [
+    Amphipod::Amber,
+    Amphipod::Bronze,
+    Amphipod::Copper,
+    Amphipod::Desert,
+];
+
+/**
+ * The rows inserted between the first and the last row of every room in part 2.
+ */
+const UNFOLDED_ROWS: [&str; 2] = ["DCBA", "DBAC"];
+
+/**
+ * The hallway position right outside a room.
+ */
+fn door(room: usize) -> usize {
+    2 + 2 * room
+}
+
+fn is_door(position: usize) -> bool {
+    (0..ROOMS).any(|room| door(room) == position)
+}
+
+/**
+ * An arrangement of the amphipods. `cells` has the 11 positions of the hallway from left to right,
+ * followed by the cells of each room from top (next to the hallway) to bottom.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    depth: usize,
+    cells: Vec<Option<Amphipod>>,
+}
+
+impl Burrow {
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn hallway(&self) -> &[Option<Amphipod>] {
+        &self.cells[..HALLWAY_LENGTH]
+    }
+
+    pub fn room(&self, room: usize) -> &[Option<Amphipod>] {
+        let start = HALLWAY_LENGTH + room * self.depth;
+        &self.cells[start..start + self.depth]
+    }
+
+    fn room_cell(&self, room: usize, depth: usize) -> usize {
+        HALLWAY_LENGTH + room * self.depth + depth
+    }
+
+    /**
+     * Whether amphipods can move into the room, because it only has amphipods that belong there.
+     */
+    fn accepts(&self, room: usize) -> bool {
+        self.room(room)
+            .iter()
+            .all(|cell| cell.is_none_or(|amphipod| amphipod.room() == room))
+    }
+
+    pub fn is_organized(&self) -> bool {
+        (0..ROOMS).all(|room| {
+            self.room(room)
+                .iter()
+                .all(|cell| *cell == Some(ROOM_TYPES[room]))
+        })
+    }
+
+    /**
+     * Whether the hallway is empty between `from` and `to`, not counting `from` itself (which is
+     * where the amphipod moving is).
+     */
+    fn is_clear(&self, from: usize, to: usize) -> bool {
+        let path = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+
+        path.into_iter()
+            .all(|position| self.cells[position].is_none())
+    }
+
+    fn moved(&self, from: usize, to: usize) -> Burrow {
+        let mut burrow = self.clone();
+        burrow.cells.swap(from, to);
+        burrow
+    }
+
+    /**
+     * Every arrangement reachable with a single move, with the energy it takes. Moving an amphipod
+     * into its own room is always part of some cheapest solution, so when that's possible it's the
+     * only move returned, which prunes the search a lot.
+     */
+    pub fn moves(&self) -> Vec<(Burrow, usize)> {
+        for position in 0..HALLWAY_LENGTH {
+            if let Some(amphipod) = self.cells[position] {
+                let room = amphipod.room();
+
+                if self.accepts(room) && self.is_clear(position, door(room)) {
+                    let depth = self.room(room).iter().filter(|cell| cell.is_none()).count() - 1;
+                    let steps = position.abs_diff(door(room)) + depth + 1;
+
+                    return vec![(
+                        self.moved(position, self.room_cell(room, depth)),
+                        steps * amphipod.energy(),
+                    )];
+                }
+            }
+        }
+
+        let mut moves = vec![];
+
+        for room in (0..ROOMS).filter(|room| !self.accepts(*room)) {
+            let (depth, amphipod) = self
+                .room(room)
+                .iter()
+                .enumerate()
+                .find_map(|(depth, cell)| cell.map(|amphipod| (depth, amphipod)))
+                .unwrap();
+
+            for position in (0..HALLWAY_LENGTH).filter(|position| !is_door(*position)) {
+                if self.is_clear(door(room), position) {
+                    let steps = depth + 1 + door(room).abs_diff(position);
+                    moves.push((
+                        self.moved(self.room_cell(room, depth), position),
+                        steps * amphipod.energy(),
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    /**
+     * A lower bound of the energy left to organize the burrow: each amphipod that has to move goes
+     * straight to its room, as if nothing was in the way.
+     */
+    fn estimate(&self) -> usize {
+        let mut energy = 0;
+
+        for (position, cell) in self.hallway().iter().enumerate() {
+            if let Some(amphipod) = cell {
+                energy += (position.abs_diff(door(amphipod.room())) + 1) * amphipod.energy();
+            }
+        }
+
+        for room in 0..ROOMS {
+            let cells = self.room(room);
+
+            for (depth, cell) in cells.iter().enumerate() {
+                if let Some(amphipod) = cell {
+                    let target = amphipod.room();
+                    let blocking = cells[depth..]
+                        .iter()
+                        .any(|below| below.is_some_and(|below| below.room() != room));
+
+                    // Leave the room, walk to the target room (at least one step aside and back
+                    // when it's already there) and step in
+                    let steps = if target != room {
+                        depth + 1 + door(room).abs_diff(door(target)) + 1
+                    } else if blocking {
+                        depth + 1 + 2 + 1
+                    } else {
+                        0
+                    };
+                    energy += steps * amphipod.energy();
+                }
+            }
+        }
+
+        energy
+    }
+
+    /**
+     * The burrow of part 2, with the two rows from the folded part of the diagram inserted between
+     * the first and the last row of every room.
+     */
+    pub fn unfold(&self) -> Burrow {
+        let depth = self.depth + UNFOLDED_ROWS.len();
+        let mut cells = self.hallway().to_vec();
+
+        for room in 0..ROOMS {
+            let original = self.room(room);
+            cells.push(original[0]);
+            cells.extend(
+                UNFOLDED_ROWS
+                    .iter()
+                    .map(|row| Amphipod::from_char(row.as_bytes()[room] as char)),
+            );
+            cells.extend(&original[1..]);
+        }
+
+        Burrow { depth, cells }
+    }
+}
+
+/**
+ * The burrow diagram is a hallway line (usually empty) followed by one line per row of the rooms,
+ * surrounded by walls:
+ *
+ * ```text
+ * #############
+ * #...........#
+ * ###B#C#B#D###
+ *   #A#D#C#A#
+ *   #########
+ * ```
+ *
+ * Only the cells are checked, the walls are ignored. Rooms can be of any depth, but there have to
+ * be as many amphipods of each type as cells in a room, or they could never be organized. Amphipods
+ * in a room have to be at its bottom, with no empty cells below them.
+ */
+impl std::str::FromStr for Burrow {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let cell = |line: usize, column: usize| {
+            let char = lines
+                .get(line)
+                .and_then(|line| line.chars().nth(column))
+                .unwrap_or(' ');
+
+            match char {
+                '.' => Ok(None),
+                _ => Amphipod::from_char(char).map(Some).ok_or_else(|| {
+                    ParseError::new(
+                        line + 1,
+                        column + 1,
+                        format!("Expected an amphipod or `.`, found `{}`", char),
+                    )
+                }),
+            }
+        };
+
+        let mut cells = (0..HALLWAY_LENGTH)
+            .map(|position| cell(1, position + 1))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Room rows go on until the bottom wall, anything else in them has to be a cell
+        let depth = lines
+            .iter()
+            .skip(2)
+            .take_while(|line| !matches!(line.chars().nth(door(0) + 1), None | Some('#')))
+            .count();
+        if depth == 0 {
+            return Err(ParseError::new(3, door(0) + 2, "The burrow has no rooms"));
+        }
+
+        for room in 0..ROOMS {
+            for line in 2..2 + depth {
+                let cell = cell(line, door(room) + 1)?;
+                // Amphipods only ever stop at the bottom of a room, on top of each other
+                if line > 2 && cell.is_none() && matches!(cells.last(), Some(Some(_))) {
+                    return Err(ParseError::new(
+                        line + 1,
+                        door(room) + 2,
+                        "Expected an amphipod, rooms can't have gaps below one",
+                    ));
+                }
+                cells.push(cell);
+            }
+        }
+
+        for amphipod in ROOM_TYPES {
+            if cells.iter().filter(|cell| **cell == Some(amphipod)).count() != depth {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    format!(
+                        "Expected {} amphipods of type {}, one for each cell of a room",
+                        depth,
+                        amphipod.as_char()
+                    ),
+                ));
+            }
+        }
+
+        Ok(Burrow { depth, cells })
+    }
+}
+
+/**
+ * Prints the burrow the same way as the input.
+ */
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let char = |cell: &Option<Amphipod>| cell.map_or('.', Amphipod::as_char);
+
+        writeln!(f, "#############")?;
+        writeln!(
+            f,
+            "#{}#",
+            self.hallway().iter().map(char).collect::<String>()
+        )?;
+
+        for depth in 0..self.depth {
+            let (left, right) = if depth == 0 {
+                ("###", "###")
+            } else {
+                ("  #", "#")
+            };
+            let cells: Vec<String> = (0..ROOMS)
+                .map(|room| char(&self.room(room)[depth]).to_string())
+                .collect();
+            writeln!(f, "{}{}{}", left, cells.join("#"), right)?;
+        }
+
+        write!(f, "  #########")
+    }
+}
+
+/**
+ * The least energy to organize the burrow, using A* over all the possible arrangements. `None` if
+ * it can't be done.
+ */
+pub fn least_energy(burrow: &Burrow) -> Option<usize> {
+    a_star(
+        burrow.clone(),
+        Burrow::moves,
+        Burrow::estimate,
+        Burrow::is_organized,
+    )
+    .map(|path| path.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let burrow = Day23::parse(SAMPLE).unwrap();
+        assert_eq!(burrow.depth(), 2);
+        assert_eq!(
+            burrow.room(0),
+            [Some(Amphipod::Bronze), Some(Amphipod::Amber)]
+        );
+        assert_eq!(burrow.to_string(), SAMPLE.trim_end());
+
+        let error = Day23::parse(&SAMPLE.replace("#C#B#D", "#C#X#D")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 8));
+        assert!(Day23::parse(&SAMPLE.replace("#C#B#D", "#C#A#D")).is_err());
+
+        let error = Day23::parse(&SAMPLE.replace("###B#C", "###X#C")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 4));
+        let error = Day23::parse(&SAMPLE.replace("#A#D#C", "#.#D#C")).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 4));
+        let error = Day23::parse(
+            &SAMPLE
+                .replace("#...........#", "#.A.........#")
+                .replace("#A#D#C", "#.#D#C"),
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 4));
+    }
+
+    #[test]
+    fn test_unfold() {
+        let burrow = Day23::parse(SAMPLE).unwrap().unfold();
+        assert_eq!(
+            burrow.to_string(),
+            "#############\n\
+             #...........#\n\
+             ###B#C#B#D###\n  \
+             #D#C#B#A#\n  \
+             #D#B#A#C#\n  \
+             #A#D#C#A#\n  \
+             #########"
+        );
+    }
+
+    #[test]
+    fn test_least_energy() {
+        let organized = Day23::parse(
+            &SAMPLE
+                .replace("B#C#B#D", "A#B#C#D")
+                .replace("A#D#C#A", "A#B#C#D"),
+        )
+        .unwrap();
+        assert_eq!(least_energy(&organized), Some(0));
+
+        let burrow = Day23::parse(SAMPLE).unwrap();
+        assert_eq!(least_energy(&burrow), Some(12521));
+        assert_eq!(least_energy(&burrow.unfold()), Some(44169));
+    }
+}
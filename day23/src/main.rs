@@ -0,0 +1,5 @@
+use day23::{Day23, INPUT, SAMPLE};
+
+fn main() {
+    aoc_common::solution::run::<Day23>(SAMPLE, INPUT);
+}
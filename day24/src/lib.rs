@@ -0,0 +1,676 @@
+use std::{fmt, str::FromStr};
+
+use aoc_common::{
+    parse::{parse_lines, parse_token},
+    Answers, Assumption, ParseError, Solution,
+};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("- (not MONAD)"),
+    part_2: Some("- (not MONAD)"),
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: None,
+    part_2: None,
+};
+
+/**
+ * --- Day 24: Arithmetic Logic Unit ---
+ *
+ * The input is a program (MONAD) for an ALU with four registers (`w`, `x`, `y` and `z`) and six
+ * instructions: `inp` reads the next digit of the input, and `add`, `mul`, `div`, `mod` and `eql`
+ * store the result of the operation in their first operand. MONAD reads a 14 digit model number
+ * (without zeroes) and the number is valid if `z` ends up being 0. Part 1 asks for the largest
+ * valid model number, and part 2 for the smallest one.
+ *
+ * There are far too many model numbers to try them all with the interpreter (`Alu`), so the answer
+ * comes from the structure of the program instead. It's 14 blocks of 18 instructions, one per
+ * digit, which only differ in three constants. Written in a higher level language each block does:
+ *
+ * ```text
+ * x = z % 26 + check != digit
+ * z = z / divisor         (divisor is 1 or 26)
+ * if x { z = z * 26 + digit + offset }
+ * ```
+ *
+ * So `z` is a stack of base 26 "digits". Blocks that divide by 1 always push `digit + offset`,
+ * because their `check` is too big to ever match a digit. Blocks that divide by 26 pop the last
+ * value, and they only avoid pushing a new value if their digit is that value plus their `check`.
+ * There are as many pushes as pops, so `z` is only 0 at the end if every pop avoids pushing again,
+ * which means that the digit of every popping block is the digit of the block it pops plus a
+ * constant. `analyze` checks that the program follows this structure and extracts the constants,
+ * and `constraints` pairs the blocks to find those differences. Then the largest (or smallest)
+ * number is found by maximizing (or minimizing) each pair of digits independently.
+ *
+ * Parsing only reads the instructions, so any ALU program can be loaded and run (`--run`), and the
+ * analysis is a separate step done by each part. Programs that aren't MONAD, like the sample (the
+ * example from the puzzle converting a number to binary), have no model number, and `aoc lint`
+ * explains why.
+ */
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Instruction>;
+    type Output1 = ModelNumber;
+    type Output2 = ModelNumber;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, str::parse)
+    }
+
+    fn part_1(program: &Self::Input) -> ModelNumber {
+        ModelNumber::find(program, Goal::Largest)
+    }
+
+    fn part_2(program: &Self::Input) -> ModelNumber {
+        ModelNumber::find(program, Goal::Smallest)
+    }
+
+    fn lint(program: &Self::Input) -> Vec<Assumption> {
+        vec![Assumption::new(
+            "The program is MONAD, with constraints between pairs of digits",
+            Monad::analyze(program).err().map(|error| error.to_string()),
+        )]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(isize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+fn parse_register(line: &str, token: &str) -> Result<Register, ParseError> {
+    match token {
+        "w" => Ok(Register::W),
+        "x" => Ok(Register::X),
+        "y" => Ok(Register::Y),
+        "z" => Ok(Register::Z),
+        _ => Err(ParseError::at(
+            line,
+            token,
+            "Expected a register (w, x, y or z)",
+        )),
+    }
+}
+
+fn parse_operand(line: &str, token: &str) -> Result<Operand, ParseError> {
+    parse_register(line, token)
+        .map(Operand::Register)
+        .or_else(|_| parse_token(line, token).map(Operand::Number))
+}
+
+/**
+ * Instructions are one per line, with their operands separated by spaces: `inp w`, `add x -1`,
+ * `eql x w`...
+ */
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split(' ').collect();
+
+        match tokens[..] {
+            ["inp", a] => Ok(Instruction::Inp(parse_register(s, a)?)),
+            [name, a, b] => {
+                let a = parse_register(s, a)?;
+                let b = parse_operand(s, b)?;
+
+                match name {
+                    "add" => Ok(Instruction::Add(a, b)),
+                    "mul" => Ok(Instruction::Mul(a, b)),
+                    "div" => Ok(Instruction::Div(a, b)),
+                    "mod" => Ok(Instruction::Mod(a, b)),
+                    "eql" => Ok(Instruction::Eql(a, b)),
+                    _ => Err(ParseError::at(
+                        s,
+                        name,
+                        format!("Unknown instruction `{}`", name),
+                    )),
+                }
+            }
+            _ => Err(ParseError::at(
+                s,
+                s,
+                "Expected `inp` with a register, or an instruction with two operands",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+/**
+ * Instructions are displayed the same way they're parsed.
+ */
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (name, a, b) = match *self {
+            Instruction::Inp(a) => return write!(f, "inp {}", a),
+            Instruction::Add(a, b) => ("add", a, b),
+            Instruction::Mul(a, b) => ("mul", a, b),
+            Instruction::Div(a, b) => ("div", a, b),
+            Instruction::Mod(a, b) => ("mod", a, b),
+            Instruction::Eql(a, b) => ("eql", a, b),
+        };
+        write!(f, "{} {} {}", name, a, b)
+    }
+}
+
+/**
+ * The interpreter. Running a program can fail with the operations the puzzle leaves undefined
+ * (dividing by 0, or `mod` with a negative number or 0), when a result doesn't fit in an `isize`,
+ * or when it reads more input than given.
+ */
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Alu {
+    pub registers: [isize; 4],
+}
+
+impl Alu {
+    pub fn new() -> Self {
+        Alu::default()
+    }
+
+    pub fn get(&self, register: Register) -> isize {
+        self.registers[register.index()]
+    }
+
+    fn value(&self, operand: Operand) -> isize {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(number) => number,
+        }
+    }
+
+    pub fn run(&mut self, program: &[Instruction], input: &[isize]) -> Result<(), String> {
+        let mut input = input.iter();
+
+        for (i, instruction) in program.iter().enumerate() {
+            let overflow = || format!("Instruction {} overflows", i + 1);
+            let (register, result) = match *instruction {
+                Instruction::Inp(a) => (
+                    a,
+                    *input
+                        .next()
+                        .ok_or_else(|| format!("Instruction {} reads past the input", i + 1))?,
+                ),
+                Instruction::Add(a, b) => (
+                    a,
+                    self.get(a)
+                        .checked_add(self.value(b))
+                        .ok_or_else(overflow)?,
+                ),
+                Instruction::Mul(a, b) => (
+                    a,
+                    self.get(a)
+                        .checked_mul(self.value(b))
+                        .ok_or_else(overflow)?,
+                ),
+                Instruction::Div(a, b) => match self.value(b) {
+                    0 => return Err(format!("Instruction {} divides by 0", i + 1)),
+                    b => (a, self.get(a).checked_div(b).ok_or_else(overflow)?),
+                },
+                Instruction::Mod(a, b) => match (self.get(a), self.value(b)) {
+                    (a_value, b_value) if a_value < 0 || b_value <= 0 => {
+                        return Err(format!(
+                            "Instruction {} calculates {} mod {}",
+                            i + 1,
+                            a_value,
+                            b_value
+                        ))
+                    }
+                    (a_value, b_value) => (a, a_value % b_value),
+                },
+                Instruction::Eql(a, b) => (a, (self.get(a) == self.value(b)) as isize),
+            };
+
+            self.registers[register.index()] = result;
+        }
+
+        Ok(())
+    }
+}
+
+pub const DIGITS: usize = 14;
+
+/**
+ * The instructions of each block of MONAD. `{}` marks the constants that change from one block to
+ * the next: the divisor, the check and the offset.
+ */
+const BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z {}", "add x {}", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y {}",
+    "mul y x", "add z y",
+];
+
+/**
+ * The constants of a single block. `pops` is whether it divides `z` by 26, and `line` is where the
+ * block starts, to report errors about the whole block.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block {
+    pub pops: bool,
+    pub check: isize,
+    pub offset: isize,
+    pub line: usize,
+}
+
+/**
+ * Why a program can't be analyzed as MONAD, and the line (starting at 1) of the instruction or
+ * block that shows it.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalysisError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl AnalysisError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        AnalysisError {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for AnalysisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/**
+ * Checks that the program is made of 14 blocks following `BLOCK`, and extracts the constants of
+ * each one. Errors point at the first instruction that doesn't match.
+ */
+pub fn analyze(program: &[Instruction]) -> Result<Vec<Block>, AnalysisError> {
+    if program.len() != DIGITS * BLOCK.len() {
+        return Err(AnalysisError::new(
+            program.len().min(DIGITS * BLOCK.len()) + 1,
+            format!(
+                "Expected {} blocks of {} instructions, one for each digit",
+                DIGITS,
+                BLOCK.len()
+            ),
+        ));
+    }
+
+    program
+        .chunks(BLOCK.len())
+        .enumerate()
+        .map(|(block, instructions)| {
+            let first_line = block * BLOCK.len() + 1;
+            let mut constants = vec![];
+
+            for (i, (instruction, expected)) in instructions.iter().zip(BLOCK).enumerate() {
+                let instruction = instruction.to_string();
+                let error = || {
+                    AnalysisError::new(
+                        first_line + i,
+                        format!(
+                            "Expected `{}`, the program doesn't look like MONAD",
+                            expected
+                        ),
+                    )
+                };
+
+                match expected.strip_suffix("{}") {
+                    Some(prefix) => {
+                        let constant = instruction.strip_prefix(prefix).ok_or_else(error)?;
+                        constants.push(constant.parse::<isize>().map_err(|_| error())?);
+                    }
+                    None if instruction == expected => {}
+                    None => return Err(error()),
+                }
+            }
+
+            let pops = match constants[0] {
+                1 => false,
+                26 => true,
+                _ => {
+                    return Err(AnalysisError::new(
+                        first_line + 4,
+                        "Expected `div z 1` or `div z 26`",
+                    ))
+                }
+            };
+
+            Ok(Block {
+                pops,
+                check: constants[1],
+                offset: constants[2],
+                line: first_line,
+            })
+        })
+        .collect()
+}
+
+/**
+ * `digits[second] = digits[first] + difference` for every valid model number.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub first: usize,
+    pub second: usize,
+    pub difference: isize,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.difference < 0 { '-' } else { '+' };
+        write!(
+            f,
+            "digit {:>2} = digit {:>2} {} {}",
+            self.second + 1,
+            self.first + 1,
+            sign,
+            self.difference.abs()
+        )
+    }
+}
+
+/**
+ * Pairs every block that pops with the block that pushed the value it pops. Programs where some
+ * pushing block could skip the push, where pops and pushes don't match, or where some pair of
+ * digits can't satisfy its constraint have no valid model number the analysis can find.
+ */
+pub fn constraints(blocks: &[Block]) -> Result<Vec<Constraint>, AnalysisError> {
+    let mut stack = vec![];
+    let mut constraints = vec![];
+
+    for (digit, block) in blocks.iter().enumerate() {
+        let error = |message: &str| Err(AnalysisError::new(block.line, message));
+
+        if !block.pops {
+            if block.check <= 9 {
+                return error(
+                    "This block could skip pushing to `z`, which the analysis doesn't support",
+                );
+            }
+            stack.push((digit, block.offset));
+            continue;
+        }
+
+        let (first, offset) = match stack.pop() {
+            Some(pushed) => pushed,
+            None => return error("This block pops from `z` when nothing was pushed"),
+        };
+        let difference = offset + block.check;
+        if difference.abs() > 8 {
+            return error("No pair of digits can satisfy the check of this block");
+        }
+
+        constraints.push(Constraint {
+            first,
+            second: digit,
+            difference,
+        });
+    }
+
+    if let Some((digit, _)) = stack.pop() {
+        return Err(AnalysisError::new(
+            blocks[digit].line,
+            "This block pushes to `z` and nothing pops it, so `z` never ends up being 0",
+        ));
+    }
+
+    Ok(constraints)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Largest,
+    Smallest,
+}
+
+/**
+ * The program and the constraints found by analyzing it.
+ */
+#[derive(Debug, Clone)]
+pub struct Monad {
+    pub program: Vec<Instruction>,
+    pub constraints: Vec<Constraint>,
+}
+
+impl Monad {
+    pub fn analyze(program: &[Instruction]) -> Result<Self, AnalysisError> {
+        let constraints = constraints(&analyze(program)?)?;
+
+        Ok(Monad {
+            program: program.to_vec(),
+            constraints,
+        })
+    }
+
+    /**
+     * The digits of the largest or smallest model number satisfying every constraint. Each pair is
+     * independent, so it's enough to pick the largest (or smallest) first digit that keeps the
+     * second one between 1 and 9.
+     */
+    pub fn digits(&self, goal: Goal) -> Vec<isize> {
+        let mut digits = vec![0; DIGITS];
+
+        for constraint in &self.constraints {
+            let first = match goal {
+                Goal::Largest => 9.min(9 - constraint.difference),
+                Goal::Smallest => 1.max(1 - constraint.difference),
+            };
+            digits[constraint.first] = first;
+            digits[constraint.second] = first + constraint.difference;
+        }
+
+        digits
+    }
+
+    /**
+     * Checks a model number with the interpreter.
+     */
+    pub fn is_valid(&self, digits: &[isize]) -> bool {
+        let mut alu = Alu::new();
+        alu.run(&self.program, digits).is_ok() && alu.get(Register::Z) == 0
+    }
+
+    pub fn model_number(&self, goal: Goal) -> String {
+        let digits = self.digits(goal);
+        assert!(
+            self.is_valid(&digits),
+            "The model number found by the analysis is not valid"
+        );
+
+        digits.iter().map(|digit| digit.to_string()).collect()
+    }
+}
+
+/**
+ * The answer to both parts, or why there's none when the program isn't MONAD.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModelNumber {
+    Found(String),
+    NotMonad(AnalysisError),
+}
+
+impl ModelNumber {
+    pub fn find(program: &[Instruction], goal: Goal) -> Self {
+        match Monad::analyze(program) {
+            Ok(monad) => ModelNumber::Found(monad.model_number(goal)),
+            Err(error) => ModelNumber::NotMonad(error),
+        }
+    }
+}
+
+impl fmt::Display for ModelNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModelNumber::Found(number) => write!(f, "{}", number),
+            ModelNumber::NotMonad(_) => write!(f, "- (not MONAD)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alu() {
+        // Stores the 4 bits of the input in w, x, y and z
+        let program = parse_lines(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\n\
+             div w 2\nmod w 2",
+            str::parse,
+        )
+        .unwrap();
+
+        let mut alu = Alu::new();
+        alu.run(&program, &[11]).unwrap();
+        assert_eq!(alu.registers, [1, 0, 1, 1]);
+
+        assert!(Alu::new().run(&program, &[]).is_err());
+        assert!(Alu::new()
+            .run(&[Instruction::Div(Register::W, Operand::Number(0))], &[])
+            .is_err());
+
+        let program = parse_lines("inp w\nmul w w\nadd w 1", str::parse).unwrap();
+        assert!(Alu::new().run(&program, &[isize::MAX]).is_err());
+        let program = parse_lines("inp w\nadd w 1", str::parse).unwrap();
+        assert_eq!(
+            Alu::new().run(&program, &[isize::MAX]),
+            Err("Instruction 2 overflows".to_string())
+        );
+    }
+
+    /**
+     * A MONAD with the given divisor, check and offset for each block.
+     */
+    fn monad(constants: &[(isize, isize, isize)]) -> Vec<Instruction> {
+        let source: Vec<String> = constants
+            .iter()
+            .flat_map(|&(divisor, check, offset)| {
+                let mut constants = vec![divisor, check, offset].into_iter();
+                BLOCK.iter().map(move |line| match line.strip_suffix("{}") {
+                    Some(prefix) => format!("{}{}", prefix, constants.next().unwrap()),
+                    None => line.to_string(),
+                })
+            })
+            .collect();
+
+        Day24::parse(&source.join("\n")).unwrap()
+    }
+
+    const CONSTANTS: [(isize, isize, isize); DIGITS] = [
+        (1, 15, 13),
+        (1, 14, 6),
+        (1, 11, 6),
+        (26, -8, 6),
+        (1, 15, 3),
+        (1, 15, 5),
+        (26, -4, 1),
+        (26, 3, 15),
+        (1, 15, 4),
+        (1, 10, 6),
+        (26, 1, 15),
+        (26, -3, 16),
+        (26, -12, 9),
+        (26, -16, 11),
+    ];
+
+    #[test]
+    fn test_sample() {
+        let program = Day24::parse(SAMPLE).unwrap();
+        let mut alu = Alu::new();
+        alu.run(&program, &[6]).unwrap();
+        assert_eq!(alu.registers, [0, 1, 1, 0]);
+
+        let part_1 = Day24::part_1(&program);
+        assert!(matches!(
+            part_1,
+            ModelNumber::NotMonad(AnalysisError { line: 12, .. })
+        ));
+        assert!(!Day24::lint(&program)[0].holds());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = "add x v".parse::<Instruction>().unwrap_err();
+        assert_eq!(error.column, 7);
+        assert!("inp 1".parse::<Instruction>().is_err());
+        assert!("sub x 1".parse::<Instruction>().is_err());
+        assert_eq!(
+            "add x -1".parse::<Instruction>().unwrap().to_string(),
+            "add x -1"
+        );
+    }
+
+    #[test]
+    fn test_analysis() {
+        let program = monad(&CONSTANTS);
+        let monad = Monad::analyze(&program).unwrap();
+        assert_eq!(monad.constraints.len(), 7);
+        assert_eq!(monad.constraints[0].to_string(), "digit  4 = digit  3 - 2");
+
+        let largest = monad.digits(Goal::Largest);
+        assert!(monad.is_valid(&largest));
+
+        // Breaking any constraint makes the number invalid
+        let mut invalid = largest.clone();
+        invalid[monad.constraints[0].second] -= 1;
+        assert!(!monad.is_valid(&invalid));
+
+        assert_eq!(Day24::part_1(&program).to_string(), "99973899829936");
+        assert_eq!(Day24::part_2(&program).to_string(), "47311127118211");
+
+        let mut changed = program.clone();
+        changed[9] = "add y 24".parse().unwrap();
+        assert_eq!(Monad::analyze(&changed).unwrap_err().line, 10);
+    }
+}
@@ -0,0 +1,71 @@
+use std::{env, process};
+
+use aoc_common::{input::Source, Solution};
+use day24::{Alu, Day24, Instruction, Monad, INPUT, SAMPLE};
+
+/**
+ * Besides the answers, `--analyze` prints the constraints found between the digits of the model
+ * number, and `--run 1,2,3` runs the program with those numbers as its input and prints the
+ * registers, for the program given as the next argument (the embedded one by default).
+ */
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("--analyze") => analyze(&read_program(args.get(1))),
+        Some("--run") => match args.get(1).map(|input| parse_input(input)) {
+            Some(Ok(input)) => run(&read_program(args.get(2)), &input),
+            _ => {
+                eprintln!("Error: --run needs the input numbers separated by commas, like 1,2,3");
+                process::exit(1);
+            }
+        },
+        _ => aoc_common::solution::run::<Day24>(SAMPLE, INPUT),
+    }
+}
+
+fn read_program(arg: Option<&String>) -> Vec<Instruction> {
+    let source = Source::from_arg(arg.map(String::as_str));
+    let input = source.read(SAMPLE, INPUT).unwrap_or_else(|error| {
+        eprintln!("Error reading {}: {}", source, error);
+        process::exit(1);
+    });
+
+    Day24::parse(&input).unwrap_or_else(|error| {
+        eprintln!("Error parsing input: {}", error);
+        process::exit(1);
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<isize>, std::num::ParseIntError> {
+    input
+        .split(',')
+        .map(|number| number.trim().parse())
+        .collect()
+}
+
+fn analyze(program: &[Instruction]) {
+    match Monad::analyze(program) {
+        Ok(monad) => {
+            for constraint in &monad.constraints {
+                println!("{}", constraint);
+            }
+        }
+        Err(error) => {
+            eprintln!("Error analyzing the program: {}", error);
+            process::exit(1);
+        }
+    }
+}
+
+fn run(program: &[Instruction], input: &[isize]) {
+    let mut alu = Alu::new();
+
+    if let Err(error) = alu.run(program, input) {
+        eprintln!("Error running the program: {}", error);
+        process::exit(1);
+    }
+
+    let [w, x, y, z] = alu.registers;
+    println!("w = {}\nx = {}\ny = {}\nz = {}", w, x, y, z);
+}
@@ -0,0 +1,211 @@
+use std::{collections::HashSet, fmt};
+
+use aoc_common::{grid::Position, solution::Unsolved, Answers, Grid, ParseError, Solution};
+
+pub const SAMPLE: &str = include_str!("../sample.txt");
+pub const INPUT: &str = include_str!("../input.txt");
+
+pub const SAMPLE_ANSWERS: Answers = Answers {
+    part_1: Some("58"),
+    part_2: None,
+};
+
+pub const INPUT_ANSWERS: Answers = Answers {
+    part_1: None,
+    part_2: None,
+};
+
+/**
+ * --- Day 25: Sea Cucumber ---
+ *
+ * Two herds of sea cucumbers share the sea floor: one only moves east (`>`) and the other only
+ * moves south (`v`). In each step the east-facing herd moves first, all at the same time, and then
+ * the south-facing herd does the same. A sea cucumber moves one position if that position is empty
+ * before its herd moves, and the sea floor wraps around, so moving past the right edge leads to
+ * the left one and moving past the bottom edge leads to the top one. The problem asks for the first
+ * step in which no sea cucumber moves.
+ *
+ * This is simulated as it is described. Each herd finds every sea cucumber that can move before
+ * moving any of them, which is what makes them all move at the same time. Not every sea floor
+ * settles (a lone `>` in an empty row goes around forever), so the simulation also stops when the
+ * sea floor gets back to a state it's already been in. There's no second part for this day, the
+ * last star comes from finishing all the others.
+ */
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = SeaFloor;
+    type Output1 = Settling;
+    type Output2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part_1(sea_floor: &Self::Input) -> Settling {
+        sea_floor.settle()
+    }
+
+    fn part_2(_: &Self::Input) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cell {
+    Empty,
+    East,
+    South,
+}
+
+impl Cell {
+    fn from_char(char: char) -> Option<Cell> {
+        match char {
+            '.' => Some(Cell::Empty),
+            '>' => Some(Cell::East),
+            'v' => Some(Cell::South),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Empty => write!(f, "."),
+            Cell::East => write!(f, ">"),
+            Cell::South => write!(f, "v"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct SeaFloor {
+    cells: Grid<Cell>,
+}
+
+impl SeaFloor {
+    pub fn get(&self, position: Position) -> Cell {
+        self.cells[position]
+    }
+
+    /**
+     * Steps until no sea cucumber moves, or until the sea floor repeats itself, in which case it
+     * never settles.
+     */
+    pub fn settle(&self) -> Settling {
+        let mut sea_floor = self.clone();
+        let mut seen = HashSet::from([self.cells.clone()]);
+        let mut steps = 1;
+
+        while sea_floor.step() {
+            if !seen.insert(sea_floor.cells.clone()) {
+                return Settling::Never;
+            }
+
+            steps += 1;
+        }
+
+        Settling::After(steps)
+    }
+
+    /**
+     * Moves both herds, east first. Returns whether any sea cucumber moved.
+     */
+    pub fn step(&mut self) -> bool {
+        let (width, height) = (self.cells.width(), self.cells.height());
+        let east = self.move_herd(Cell::East, |position| {
+            Position::new((position.x + 1) % width, position.y)
+        });
+        let south = self.move_herd(Cell::South, |position| {
+            Position::new(position.x, (position.y + 1) % height)
+        });
+        east || south
+    }
+
+    fn move_herd(&mut self, herd: Cell, next: impl Fn(Position) -> Position) -> bool {
+        let moves: Vec<(Position, Position)> = self
+            .cells
+            .iter()
+            .filter(|(_, cell)| **cell == herd)
+            .map(|(position, _)| (position, next(position)))
+            .filter(|(_, next)| self.cells[*next] == Cell::Empty)
+            .collect();
+
+        for (from, to) in &moves {
+            self.cells[*from] = Cell::Empty;
+            self.cells[*to] = herd;
+        }
+
+        !moves.is_empty()
+    }
+}
+
+/**
+ * The sea floor is a rectangle of `>`, `v` and `.` (empty positions).
+ */
+impl std::str::FromStr for SeaFloor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(SeaFloor {
+            cells: Grid::parse(s, Cell::from_char)?,
+        })
+    }
+}
+
+impl fmt::Debug for SeaFloor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
+
+/**
+ * The answer to part 1: the first step in which no sea cucumber moves, if there's one.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Settling {
+    After(usize),
+    Never,
+}
+
+impl fmt::Display for Settling {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Settling::After(steps) => write!(f, "{}", steps),
+            Settling::Never => write!(f, "- (never settles)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_step() {
+        let mut sea_floor: SeaFloor =
+            "...>...\n.......\n......>\nv.....>\n......>\n.......\n..vvv.."
+                .parse()
+                .unwrap();
+        sea_floor.step();
+        assert_eq!(
+            format!("{:?}", sea_floor),
+            "..vv>..\n.......\n>......\nv.....>\n>......\n.......\n....v..\n"
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let sea_floor = Day25::parse(SAMPLE).unwrap();
+        assert_eq!(format!("{:?}", sea_floor), SAMPLE);
+        assert_eq!(Day25::part_1(&sea_floor), Settling::After(58));
+
+        let error = Day25::parse("..>\n.x.").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let sea_floor = Day25::parse("..>..\n.....").unwrap();
+        assert_eq!(sea_floor.settle(), Settling::Never);
+        assert_eq!(Day25::part_1(&sea_floor).to_string(), "- (never settles)");
+    }
+}
@@ -0,0 +1,5 @@
+use day25::{Day25, INPUT, SAMPLE};
+
+fn main() {
+    aoc_common::solution::run::<Day25>(SAMPLE, INPUT);
+}